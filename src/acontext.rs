@@ -1,5 +1,4 @@
 use super::*;
-use num::NumCast;
 use std::any::Any;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -13,7 +12,7 @@ lazy_static! {
 }
 
 #[derive(Debug)]
-struct AContextInner<S: Float> {
+struct AContextInner<S: Scalar> {
     cid: usize,
     pub indeps: Vec<usize>,
     pub deps: Vec<usize>,
@@ -21,7 +20,7 @@ struct AContextInner<S: Float> {
     pub vals: Vec<S>,
 }
 
-impl<S: Float> AContextInner<S> {
+impl<S: Scalar> AContextInner<S> {
     /// Construct a raw AContextInner
     fn construct(cid: usize) -> Self {
         AContextInner {
@@ -52,7 +51,7 @@ impl<S: Float> AContextInner<S> {
     }
 }
 
-impl<S: Float> Drop for AContextInner<S> {
+impl<S: Scalar> Drop for AContextInner<S> {
     fn drop(&mut self) {
         let mut ctx_map = CONTEXT_MAP.lock().unwrap();
         ctx_map.remove(&self.cid());
@@ -60,11 +59,11 @@ impl<S: Float> Drop for AContextInner<S> {
 }
 
 /// Records a function evaluation
-pub struct AContext<S: Float> {
+pub struct AContext<S: Scalar> {
     inner: Arc<Mutex<AContextInner<S>>>,
 }
 
-impl<S: Float> AContext<S> {
+impl<S: Scalar> AContext<S> {
     /// Create a new AContext
     pub fn new() -> Self {
         AContext {
@@ -89,11 +88,47 @@ impl<S: Float> AContext<S> {
         inner.cid()
     }
 
+    /// Record an operation
+    pub fn record(
+        &mut self,
+        opcode: OpCode,
+        val: S,
+        arg1: Option<usize>,
+        arg2: Option<usize>,
+    ) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        let vid = inner.vals.len();
+        inner.vals.push(val);
+        inner.ops.push(Operation {
+            opcode,
+            vid,
+            arg1,
+            arg2,
+        });
+        vid
+    }
+
+    /// Get a tape
+    pub fn tape(&self) -> impl Tape<S> + Clone {
+        let inner = self.inner.lock().unwrap();
+        AContextTape {
+            indeps: inner.indeps.clone(),
+            deps: inner.deps.clone(),
+            ops: inner.ops.clone(),
+            vals: inner.vals.clone(),
+        }
+    }
+}
+
+// These methods revolve around `AFloat<S>`, which is only defined for `S: Float` (it implements
+// the entire `num::Float` surface, transcendental ops included), so they stay gated on the full
+// `Float` bound rather than the minimal `Scalar` one above.
+impl<S: Float> AContext<S> {
     /// Mark a variable as independent
     pub fn set_indep(&mut self, x: &mut AFloat<S>) {
         let mut inner = self.inner.lock().unwrap();
         let vid = inner.vals.len();
-        inner.vals.push(NumCast::from(x.value()).unwrap());
+        inner.vals.push(x.value());
         x.set_context(inner.cid(), vid);
         inner.indeps.push(vid);
     }
@@ -109,7 +144,7 @@ impl<S: Float> AContext<S> {
             None => {
                 // Record constant
                 let vid = inner.vals.len();
-                inner.vals.push(NumCast::from(x.value()).unwrap());
+                inner.vals.push(x.value());
                 inner.ops.push(Operation {
                     opcode: OpCode::Const,
                     vid,
@@ -144,54 +179,23 @@ impl<S: Float> AContext<S> {
             self.set_dep(x);
         }
     }
-
-    /// Record an operation
-    pub fn record(
-        &mut self,
-        opcode: OpCode,
-        val: S,
-        arg1: Option<usize>,
-        arg2: Option<usize>,
-    ) -> usize {
-        let mut inner = self.inner.lock().unwrap();
-        let vid = inner.vals.len();
-        inner.vals.push(NumCast::from(val).unwrap());
-        inner.ops.push(Operation {
-            opcode,
-            vid,
-            arg1,
-            arg2,
-        });
-        vid
-    }
-
-    /// Get a tape
-    pub fn tape(&self) -> impl Tape<S> + Clone {
-        let inner = self.inner.lock().unwrap();
-        AContextTape {
-            indeps: inner.indeps.clone(),
-            deps: inner.deps.clone(),
-            ops: inner.ops.clone(),
-            vals: inner.vals.clone(),
-        }
-    }
 }
 
-impl<S: Float> Default for AContext<S> {
+impl<S: Scalar> Default for AContext<S> {
     fn default() -> Self {
         Self::new()
     }
 }
 
 #[derive(Debug, Clone)]
-struct AContextTape<S: Float> {
+struct AContextTape<S: Scalar> {
     indeps: Vec<usize>,
     deps: Vec<usize>,
     ops: Vec<Operation>,
     vals: Vec<S>,
 }
 
-impl<S: Float> Tape<S> for AContextTape<S> {
+impl<S: Scalar> Tape<S> for AContextTape<S> {
     fn indeps(&self) -> &[usize] {
         &self.indeps
     }
@@ -0,0 +1,384 @@
+use super::*;
+use num::traits::{Num, NumCast, One, ToPrimitive, Zero};
+
+/// A dual number carrying `K` directional derivatives ("tangents") alongside its value, for
+/// batched tapeless forward-mode automatic differentiation
+///
+/// Unlike [`ADouble`], which propagates a single tangent per evaluation, `ADoubleK` carries a
+/// stack-allocated `[f64; K]` tangent block and propagates all `K` directions through every
+/// arithmetic operation in a single pass, so a [`Function`] only has to be evaluated once per
+/// block of `K` input directions instead of once per direction (see
+/// [`jacobian_forward_blocked`]).
+#[derive(Clone, Copy, Debug)]
+pub struct ADoubleK<const K: usize> {
+    v: f64,
+    dv: [f64; K],
+}
+
+impl<const K: usize> ADoubleK<K> {
+    /// Create a variable from its zero-order value and tangent block
+    pub fn new(v: f64, dv: [f64; K]) -> Self {
+        Self { v, dv }
+    }
+
+    /// Create an independent variable seeding tangent direction `k` with `1.0`
+    pub fn seed(v: f64, k: usize) -> Self {
+        let mut dv = [0.0; K];
+        dv[k] = 1.0;
+        Self { v, dv }
+    }
+
+    /// Get the zero-order value
+    pub fn value(&self) -> f64 {
+        self.v
+    }
+
+    /// Get the tangent block
+    pub fn dvalue(&self) -> &[f64; K] {
+        &self.dv
+    }
+
+    /// Apply a unary operation, scaling the tangent block by `dvda = df/da`
+    fn unary(self, v: f64, dvda: f64) -> Self {
+        let mut dv = self.dv;
+        for x in dv.iter_mut() {
+            *x *= dvda;
+        }
+        Self { v, dv }
+    }
+}
+
+impl<const K: usize> From<f64> for ADoubleK<K> {
+    fn from(scalar: f64) -> Self {
+        Self::new(scalar, [0.0; K])
+    }
+}
+
+impl<const K: usize> std::cmp::PartialEq<ADoubleK<K>> for ADoubleK<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.v.eq(&other.v)
+    }
+}
+
+impl<const K: usize> std::cmp::PartialOrd<ADoubleK<K>> for ADoubleK<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.v.partial_cmp(&other.v)
+    }
+}
+
+impl<const K: usize> std::ops::Neg for ADoubleK<K> {
+    type Output = ADoubleK<K>;
+    fn neg(self) -> Self {
+        self.unary(-self.v, -1.0)
+    }
+}
+
+macro_rules! binary_op {
+    ($op:ident, $method:ident, |$av:ident, $bv:ident| $v:expr, |$ad:ident, $bd:ident| $dv:expr) => {
+        impl<const K: usize> std::ops::$op<ADoubleK<K>> for ADoubleK<K> {
+            type Output = ADoubleK<K>;
+            fn $method(self, rhs: Self) -> Self {
+                let $av = self.v;
+                let $bv = rhs.v;
+                let v = $v;
+                let mut dv = [0.0; K];
+                for i in 0..K {
+                    let $ad = self.dv[i];
+                    let $bd = rhs.dv[i];
+                    dv[i] = $dv;
+                }
+                Self::new(v, dv)
+            }
+        }
+
+        impl<const K: usize> std::ops::$op<f64> for ADoubleK<K> {
+            type Output = ADoubleK<K>;
+            fn $method(self, rhs: f64) -> Self {
+                self.$method(ADoubleK::from(rhs))
+            }
+        }
+
+        impl<const K: usize> std::ops::$op<ADoubleK<K>> for f64 {
+            type Output = ADoubleK<K>;
+            fn $method(self, rhs: ADoubleK<K>) -> ADoubleK<K> {
+                ADoubleK::from(self).$method(rhs)
+            }
+        }
+    };
+}
+
+binary_op!(Add, add, |av, bv| av + bv, |ad, bd| ad + bd);
+binary_op!(Sub, sub, |av, bv| av - bv, |ad, bd| ad - bd);
+binary_op!(Mul, mul, |av, bv| av * bv, |ad, bd| ad * bv + av * bd);
+binary_op!(Div, div, |av, bv| av / bv, |ad, bd| (ad * bv - av * bd) / (bv * bv));
+
+macro_rules! assign_op {
+    ($op:ident, $method:ident, $optoken:tt) => {
+        impl<const K: usize> std::ops::$op<ADoubleK<K>> for ADoubleK<K> {
+            fn $method(&mut self, rhs: ADoubleK<K>) {
+                let result = *self $optoken rhs;
+                *self = result;
+            }
+        }
+
+        impl<const K: usize> std::ops::$op<f64> for ADoubleK<K> {
+            fn $method(&mut self, rhs: f64) {
+                let result = *self $optoken rhs;
+                *self = result;
+            }
+        }
+    }
+}
+
+assign_op!(AddAssign, add_assign, +);
+assign_op!(SubAssign, sub_assign, -);
+assign_op!(MulAssign, mul_assign, *);
+assign_op!(DivAssign, div_assign, /);
+
+impl<const K: usize> std::ops::Rem<ADoubleK<K>> for ADoubleK<K> {
+    type Output = ADoubleK<K>;
+    fn rem(self, _rhs: Self) -> Self {
+        panic!("Operation '%' unsupported on ADoubleK");
+    }
+}
+
+impl<const K: usize> ToPrimitive for ADoubleK<K> {
+    fn to_i64(&self) -> Option<i64> {
+        self.v.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.v.to_u64()
+    }
+}
+
+impl<const K: usize> NumCast for ADoubleK<K> {
+    fn from<T>(n: T) -> Option<Self>
+    where
+        T: ToPrimitive,
+    {
+        f64::from(n).map(|n| Self::new(n, [0.0; K]))
+    }
+}
+
+impl<const K: usize> Zero for ADoubleK<K> {
+    fn zero() -> Self {
+        Self::new(0.0, [0.0; K])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v.is_zero()
+    }
+}
+
+impl<const K: usize> One for ADoubleK<K> {
+    fn one() -> Self {
+        Self::new(1.0, [0.0; K])
+    }
+
+    fn is_one(&self) -> bool {
+        self.v.is_one()
+    }
+}
+
+impl<const K: usize> Num for ADoubleK<K> {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Ok(Self::new(f64::from_str_radix(str, radix)?, [0.0; K]))
+    }
+}
+
+macro_rules! float_constant {
+    ($method:ident) => {
+        fn $method() -> Self {
+            Self::new(f64::$method(), [0.0; K])
+        }
+    };
+}
+
+macro_rules! float_passthrough {
+    ($type:ty, $method:ident) => {
+        fn $method(self) -> $type {
+            self.v.$method()
+        }
+    };
+}
+
+macro_rules! float_unsupported {
+    ($type:ty, $method:ident $(, $arg_type:ty)*) => {
+        fn $method(self, $(_: $arg_type)*) -> $type {
+            panic!(concat!("Operation '", stringify!($method), "' unsupported on ADoubleK"));
+        }
+    }
+}
+
+macro_rules! float_elemental {
+    ($method:ident, |$a:ident| $v:expr, |$av:ident| $dvda:expr) => {
+        fn $method(self) -> Self {
+            let $a = self.v;
+            let v = $v;
+            let $av = self.v;
+            self.unary(v, $dvda)
+        }
+    };
+}
+
+impl<const K: usize> num::Float for ADoubleK<K> {
+    float_constant!(nan);
+    float_constant!(infinity);
+    float_constant!(neg_infinity);
+    float_constant!(neg_zero);
+    float_constant!(min_value);
+    float_constant!(min_positive_value);
+    float_constant!(max_value);
+
+    float_passthrough!(bool, is_nan);
+    float_passthrough!(bool, is_infinite);
+    float_passthrough!(bool, is_finite);
+    float_passthrough!(bool, is_normal);
+    float_passthrough!(bool, is_sign_positive);
+    float_passthrough!(bool, is_sign_negative);
+
+    float_passthrough!(std::num::FpCategory, classify);
+
+    float_unsupported!(Self, floor);
+    float_unsupported!(Self, ceil);
+    float_unsupported!(Self, round);
+    float_unsupported!(Self, trunc);
+    float_unsupported!(Self, fract);
+    float_unsupported!(Self, signum);
+    float_unsupported!(Self, exp_m1);
+    float_unsupported!(Self, ln_1p);
+    float_unsupported!(Self, sinh);
+    float_unsupported!(Self, cosh);
+    float_unsupported!(Self, tanh);
+    float_unsupported!(Self, asinh);
+    float_unsupported!(Self, acosh);
+    float_unsupported!(Self, atanh);
+    float_unsupported!(Self, atan2, Self);
+
+    fn abs(self) -> Self {
+        // Directional derivative of `|a|`, matching `Operation`'s convention of treating a kink
+        // at `a == 0` as differentiable from the right
+        let mut dv = self.dv;
+        for x in dv.iter_mut() {
+            *x = (self.v + *x).abs() - self.v.abs();
+        }
+        Self::new(self.v.abs(), dv)
+    }
+
+    float_elemental!(exp, |a| a.exp(), |av| av.exp());
+    float_elemental!(ln, |a| a.ln(), |av| 1.0 / av);
+    float_elemental!(sin, |a| a.sin(), |av| av.cos());
+    float_elemental!(cos, |a| a.cos(), |av| -av.sin());
+    float_elemental!(tan, |a| a.tan(), |av| 1.0 / (av.cos() * av.cos()));
+    float_elemental!(asin, |a| a.asin(), |av| 1.0 / (1.0 - av * av).sqrt());
+    float_elemental!(acos, |a| a.acos(), |av| -1.0 / (1.0 - av * av).sqrt());
+    float_elemental!(atan, |a| a.atan(), |av| 1.0 / (1.0 + av * av));
+
+    fn powf(self, other: Self) -> Self {
+        let x = self.v;
+        let y = other.v;
+        let v = x.powf(y);
+
+        let mut dv = [0.0; K];
+        for i in 0..K {
+            let dx = self.dv[i];
+            let dy = other.dv[i];
+            let rv1 = if dx != 0.0 {
+                y * x.powf(y - 1.0) * dx
+            } else {
+                0.0
+            };
+            let rv2 = if dy != 0.0 { x.ln() * x.powf(y) * dy } else { 0.0 };
+            dv[i] = rv1 + rv2;
+        }
+        Self::new(v, dv)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        (self * a) + b
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.powf(<Self as NumCast>::from(n).unwrap())
+    }
+
+    fn sqrt(self) -> Self {
+        self.powf(<Self as NumCast>::from(0.5).unwrap())
+    }
+
+    fn exp2(self) -> Self {
+        <Self as NumCast>::from(2.0).unwrap().powf(self)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.log(<Self as NumCast>::from(2.0).unwrap())
+    }
+
+    fn log10(self) -> Self {
+        self.log(<Self as NumCast>::from(10.0).unwrap())
+    }
+
+    fn max(self, other: Self) -> Self {
+        <Self as NumCast>::from(0.5).unwrap() * (self + other + (self - other).abs())
+    }
+
+    fn min(self, other: Self) -> Self {
+        <Self as NumCast>::from(0.5).unwrap() * (self + other - (self - other).abs())
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).abs()
+    }
+
+    fn cbrt(self) -> Self {
+        self.powf(Self::one() / <Self as NumCast>::from(3.0).unwrap())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self.powi(2) + other.powi(2)).sqrt()
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.v.integer_decode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EPS: f64 = 1e-5;
+
+    #[test]
+    fn adoublek_arithmetic() {
+        let x = ADoubleK::<2>::new(2.0, [1.0, 0.0]);
+        let y = ADoubleK::<2>::new(3.0, [0.0, 1.0]);
+        let z = x * y + x;
+
+        assert!((z.value() - 8.0).abs() < EPS);
+        assert!((z.dvalue()[0] - 4.0).abs() < EPS);
+        assert!((z.dvalue()[1] - 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn adoublek_elemental() {
+        let x = ADoubleK::<1>::seed(0.5, 0);
+        let y = x.sin();
+        assert!((y.value() - 0.5_f64.sin()).abs() < EPS);
+        assert!((y.dvalue()[0] - 0.5_f64.cos()).abs() < EPS);
+    }
+}
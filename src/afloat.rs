@@ -110,6 +110,76 @@ impl<S: Float> AFloat<S> {
     pub(crate) fn context(&self) -> Option<(usize, usize)> {
         self.ctx
     }
+
+    /// Compute the gradient of this value w.r.t. the independent variables of its recorded
+    /// context, in a single reverse sweep
+    ///
+    /// Borrows the ergonomics of reverse-mode crates exposing `result.grad().wrt(&params)`: this
+    /// resolves the `(ctx_id, val_id)` stored in [`AFloat::ctx`], runs a single reverse sweep
+    /// against that context's tape, and returns a [`Gradient`] that [`Gradient::wrt`] can then be
+    /// queried with the original [`AFloat`] handles -- removing the boilerplate of manually
+    /// cloning tapes and driving the reverse drivers by hand for the common "one scalar output,
+    /// gradient w.r.t. inputs" case.
+    ///
+    /// Panics if this value was not recorded against a live [`AContext`], i.e. neither it nor any
+    /// of its operands (transitively) was ever seeded from [`AContext::new_indep`].
+    pub fn grad(&self) -> Gradient<S> {
+        let (cid, vid) = self
+            .context()
+            .expect("AFloat::grad requires a value recorded against a live AContext");
+        let tape = AContext::<S>::from_cid(cid)
+            .expect("context was dropped before AFloat::grad was called")
+            .tape();
+        let xbar = tape.first_order_reverse_at(vid);
+        Gradient {
+            cid,
+            indeps: tape.indeps().to_vec(),
+            xbar,
+        }
+    }
+
+    /// Rectified linear unit, `max(self, 0)`
+    ///
+    /// Composed through `num::Float::max` (itself `Abs`-based) rather than `OpCode::Relu`, so a
+    /// value produced this way still decomposes into the same `Abs` op that `AbsNormalTape`
+    /// recognizes as a switching variable.
+    pub fn relu(self) -> Self {
+        num::Float::max(self, Self::new(S::zero(), S::zero()))
+    }
+}
+
+/// Gradient of a single scalar [`AFloat`] output w.r.t. the independent variables recorded
+/// alongside it, returned by [`AFloat::grad`]
+pub struct Gradient<S: Float> {
+    cid: usize,
+    indeps: Vec<usize>,
+    xbar: DVector<S>,
+}
+
+impl<S: Float> Gradient<S> {
+    /// Partial derivatives of the output w.r.t. `vars`, in the same order
+    ///
+    /// Panics if any of `vars` was not recorded against the same context this gradient was
+    /// computed from, or is not one of that context's independent variables.
+    pub fn wrt(&self, vars: &[AFloat<S>]) -> DVector<S> {
+        let mut result = DVector::zeros(vars.len());
+        for (idx, var) in vars.iter().enumerate() {
+            let (cid, vid) = var
+                .context()
+                .expect("AFloat passed to Gradient::wrt has no recorded context");
+            assert_eq!(
+                cid, self.cid,
+                "AFloat passed to Gradient::wrt belongs to a different context"
+            );
+            let pos = self
+                .indeps
+                .iter()
+                .position(|v| *v == vid)
+                .expect("AFloat passed to Gradient::wrt is not an independent variable");
+            result[idx] = self.xbar[pos];
+        }
+        result
+    }
 }
 
 impl<S: Float> std::cmp::PartialEq<AFloat<S>> for AFloat<S> {
@@ -302,21 +372,12 @@ impl<S: Float> num::Float for AFloat<S> {
 
     float_passthrough!(std::num::FpCategory, classify);
 
-    float_unsupported!(Self, floor);
-    float_unsupported!(Self, ceil);
-    float_unsupported!(Self, round);
-    float_unsupported!(Self, trunc);
-    float_unsupported!(Self, fract);
-    float_unsupported!(Self, signum);
-    float_unsupported!(Self, exp_m1);
-    float_unsupported!(Self, ln_1p);
-    float_unsupported!(Self, sinh);
-    float_unsupported!(Self, cosh);
-    float_unsupported!(Self, tanh);
-    float_unsupported!(Self, asinh);
-    float_unsupported!(Self, acosh);
-    float_unsupported!(Self, atanh);
-    float_unsupported!(Self, atan2, Self);
+    float_elemental!(floor, Floor);
+    float_elemental!(ceil, Ceil);
+    float_elemental!(round, Round);
+    float_elemental!(trunc, Trunc);
+    float_elemental!(fract, Fract);
+    float_elemental!(signum, Signum);
 
     float_elemental!(abs, Abs);
     float_elemental!(exp, Exp);
@@ -327,7 +388,16 @@ impl<S: Float> num::Float for AFloat<S> {
     float_elemental!(asin, Asin);
     float_elemental!(acos, Acos);
     float_elemental!(atan, Atan);
+    float_elemental!(sinh, Sinh);
+    float_elemental!(cosh, Cosh);
+    float_elemental!(tanh, Tanh);
+    float_elemental!(asinh, Asinh);
+    float_elemental!(acosh, Acosh);
+    float_elemental!(atanh, Atanh);
+    float_elemental!(exp_m1, ExpM1);
+    float_elemental!(ln_1p, Ln1p);
     float_elemental2!(powf, Powf);
+    float_elemental2!(atan2, Atan2);
 
     fn mul_add(self, a: Self, b: Self) -> Self {
         (self * a) + b
@@ -433,5 +503,127 @@ mod tests {
         test_case!(asin, |x: f64| 1.0 / (1.0 - x.powi(2)).sqrt());
         test_case!(acos, |x: f64| -1.0 / (1.0 - x.powi(2)).sqrt());
         test_case!(atan, |x: f64| 1.0 / (1.0 + x.powi(2)));
+        test_case!(sinh, |x: f64| x.cosh());
+        test_case!(cosh, |x: f64| x.sinh());
+        test_case!(tanh, |x: f64| 1.0 - x.tanh().powi(2));
+        test_case!(asinh, |x: f64| 1.0 / (x.powi(2) + 1.0).sqrt());
+        test_case!(exp_m1, |x: f64| x.exp());
+        test_case!(ln_1p, |x: f64| 1.0 / (1.0 + x));
+
+        let x = AFloat::<f64>::new(2.0, 1.0);
+        let y = x.acosh();
+        assert!(y.dvalue() - (1.0 / (x.value().powi(2) - 1.0).sqrt()) < EPS);
+
+        let x = AFloat::<f64>::new(0.5, 1.0);
+        let y = x.atanh();
+        assert!(y.dvalue() - (1.0 / (1.0 - x.value().powi(2))) < EPS);
+    }
+
+    #[test]
+    fn afloat_atan2() {
+        let y = AFloat::<f64>::new(1.0, 1.0);
+        let x = AFloat::<f64>::new(2.0, 0.0);
+        let z = y.atan2(x);
+        assert!((z.value() - 1.0_f64.atan2(2.0)).abs() < EPS);
+        assert!((z.dvalue() - 2.0 / (2.0_f64.powi(2) + 1.0_f64.powi(2))).abs() < EPS);
+
+        let y = AFloat::<f64>::new(1.0, 0.0);
+        let x = AFloat::<f64>::new(2.0, 1.0);
+        let z = y.atan2(x);
+        assert!((z.dvalue() - (-1.0 / (2.0_f64.powi(2) + 1.0_f64.powi(2)))).abs() < EPS);
+    }
+
+    #[test]
+    fn afloat_grad() {
+        let mut ctx = AContext::<f64>::new();
+        let x = ctx.new_indep(3.0);
+        let y = ctx.new_indep(5.0);
+        let z = x * x * y;
+        let grad = z.grad();
+        let dz = grad.wrt(&[x, y]);
+        assert!((dz[0] - 2.0 * 3.0 * 5.0).abs() < EPS);
+        assert!((dz[1] - 3.0 * 3.0).abs() < EPS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn afloat_grad_panics_without_context() {
+        let z = AFloat::<f64>::new(1.0, 0.0);
+        z.grad();
+    }
+
+    #[test]
+    fn afloat_rounding_functions() {
+        let x = AFloat::<f64>::new(2.7, 1.0);
+        assert_eq!(x.floor().value(), 2.0);
+        assert_eq!(x.floor().dvalue(), 0.0);
+        assert_eq!(x.ceil().value(), 3.0);
+        assert_eq!(x.ceil().dvalue(), 0.0);
+        assert_eq!(x.round().value(), 3.0);
+        assert_eq!(x.round().dvalue(), 0.0);
+        assert_eq!(x.trunc().value(), 2.0);
+        assert_eq!(x.trunc().dvalue(), 0.0);
+        assert!((x.fract().value() - 0.7).abs() < EPS);
+        assert_eq!(x.fract().dvalue(), 1.0);
+
+        let neg = AFloat::<f64>::new(-3.5, 1.0);
+        assert_eq!(neg.signum().value(), -1.0);
+        assert_eq!(neg.signum().dvalue(), 0.0);
+    }
+
+    #[test]
+    fn afloat_relu() {
+        let pos = AFloat::<f64>::new(2.7, 1.0);
+        assert_eq!(pos.relu().value(), 2.7);
+        assert_eq!(pos.relu().dvalue(), 1.0);
+
+        let neg = AFloat::<f64>::new(-2.7, 1.0);
+        assert_eq!(neg.relu().value(), 0.0);
+        assert_eq!(neg.relu().dvalue(), 0.0);
+    }
+
+    #[test]
+    fn operation_min_max_relu() {
+        // `OpCode::Min`/`Max`/`Relu` are only reachable by constructing `Operation`s directly --
+        // they don't feed into `AFloat`'s own arithmetic, which still goes through the Abs-based
+        // `num::Float::max`/`min` so it keeps registering as a switching variable in
+        // `AbsNormalTape`. Exercise them here at the `Operation` level instead.
+        let v = vec![3.0, 1.0, 3.0];
+        let max_op = Operation::max(3, 0, 1);
+        let min_op = Operation::min(4, 0, 1);
+        let relu_op = Operation::relu(5, 1);
+
+        let mut zero = v.clone();
+        zero.resize(6, 0.0);
+        max_op.zero_order(&mut zero);
+        min_op.zero_order(&mut zero);
+        relu_op.zero_order(&mut zero);
+        assert_eq!(zero[3], 3.0);
+        assert_eq!(zero[4], 1.0);
+        assert_eq!(zero[5], 1.0);
+
+        let mut dv = vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+        max_op.first_order(&v, &mut dv);
+        min_op.first_order(&v, &mut dv);
+        relu_op.first_order(&v, &mut dv);
+        assert_eq!(dv[3], 0.0); // max picks lhs (3.0 > 1.0), which has zero tangent
+        assert_eq!(dv[4], 1.0); // min picks rhs (1.0 < 3.0), which has unit tangent
+        assert_eq!(dv[5], 1.0); // relu passes through a positive argument unchanged
+
+        let mut vbar = vec![0.0; 6];
+        vbar[3] = 1.0;
+        vbar[4] = 1.0;
+        max_op.first_order_reverse(&v, &mut vbar);
+        min_op.first_order_reverse(&v, &mut vbar);
+        assert_eq!(vbar[0], 1.0); // max's adjoint routes entirely to lhs (3.0 is the larger one)
+        assert_eq!(vbar[1], 1.0); // min's adjoint routes entirely to rhs (1.0 is the smaller one)
+
+        // At a tie, both ops resolve `sigma` from `Signum`'s convention (`0.0.signum() == 1.0`),
+        // so `max`/`min` each deterministically pick one side instead of splitting the adjoint.
+        let tie = vec![2.0, 2.0];
+        let mut tie_vbar = vec![0.0, 0.0, 1.0];
+        Operation::max(2, 0, 1).first_order_reverse(&tie, &mut tie_vbar);
+        assert_eq!(tie_vbar[0], 1.0);
+        assert_eq!(tie_vbar[1], 0.0);
     }
 }
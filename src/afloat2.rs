@@ -0,0 +1,500 @@
+use super::*;
+use num::traits::{Num, NumCast, One, ToPrimitive, Zero};
+
+/// A hyper-dual number carrying a value, a first-order tangent and a second-order tangent, for
+/// tapeless forward-mode Hessian-vector products
+///
+/// Seeding `dv = 1, ddv = 0` on one input and evaluating yields the exact second directional
+/// derivative (curvature) of the function in that direction in `AFloat2::ddvalue`, alongside the
+/// ordinary first directional derivative in `AFloat2::dvalue` -- combined with [`AFloatN`]'s
+/// multiple tangents, this gives Hessian-vector products without ever recording a tape. Every
+/// elementary operation propagates both tangents via the second-order chain rule: for a unary `f`
+/// with derivatives `f'`, `f''`, `ddv_out = f''*dv^2 + f'*ddv`; for a binary `g(a, b)` with
+/// gradient `(g_a, g_b)` and Hessian `(g_aa, g_ab, g_bb)`,
+/// `ddv_out = g_aa*a.dv^2 + 2*g_ab*a.dv*b.dv + g_bb*b.dv^2 + g_a*a.ddv + g_b*b.ddv`.
+#[derive(Clone, Copy, Debug)]
+pub struct AFloat2<S: Float> {
+    v: S,
+    dv: S,
+    ddv: S,
+}
+
+impl<S: Float> AFloat2<S> {
+    /// Create a variable from its zero-, first- and second-order value
+    pub fn new(v: S, dv: S, ddv: S) -> Self {
+        Self { v, dv, ddv }
+    }
+
+    /// Create an independent variable, seeding `dv = 1` and `ddv = 0`
+    pub fn seed(v: S) -> Self {
+        Self::new(v, S::one(), S::zero())
+    }
+
+    /// Get the zero-order value
+    pub fn value(&self) -> S {
+        self.v
+    }
+
+    /// Get the first-order (tangent) value
+    pub fn dvalue(&self) -> S {
+        self.dv
+    }
+
+    /// Get the second-order (curvature) value
+    pub fn ddvalue(&self) -> S {
+        self.ddv
+    }
+
+    /// Apply a unary operation with value `v`, derivative `dvda = f'(a)` and second derivative
+    /// `ddvda = f''(a)`
+    fn unary(self, v: S, dvda: S, ddvda: S) -> Self {
+        let dv = dvda * self.dv;
+        let ddv = ddvda * self.dv * self.dv + dvda * self.ddv;
+        Self { v, dv, ddv }
+    }
+}
+
+impl<S: Float> From<S> for AFloat2<S> {
+    fn from(scalar: S) -> Self {
+        Self::new(scalar, S::zero(), S::zero())
+    }
+}
+
+impl<S: Float> std::cmp::PartialEq<AFloat2<S>> for AFloat2<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.v.eq(&other.v)
+    }
+}
+
+impl<S: Float> std::cmp::PartialOrd<AFloat2<S>> for AFloat2<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.v.partial_cmp(&other.v)
+    }
+}
+
+impl<S: Float> std::ops::Neg for AFloat2<S> {
+    type Output = AFloat2<S>;
+    fn neg(self) -> Self {
+        Self::new(-self.v, -self.dv, -self.ddv)
+    }
+}
+
+impl<S: Float> std::ops::Add<AFloat2<S>> for AFloat2<S> {
+    type Output = AFloat2<S>;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.v + rhs.v, self.dv + rhs.dv, self.ddv + rhs.ddv)
+    }
+}
+
+impl<S: Float> std::ops::Sub<AFloat2<S>> for AFloat2<S> {
+    type Output = AFloat2<S>;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.v - rhs.v, self.dv - rhs.dv, self.ddv - rhs.ddv)
+    }
+}
+
+impl<S: Float> std::ops::Mul<AFloat2<S>> for AFloat2<S> {
+    type Output = AFloat2<S>;
+    fn mul(self, rhs: Self) -> Self {
+        let v = self.v * rhs.v;
+        let dv = self.dv * rhs.v + self.v * rhs.dv;
+        let ddv = self.ddv * rhs.v
+            + (S::one() + S::one()) * self.dv * rhs.dv
+            + self.v * rhs.ddv;
+        Self::new(v, dv, ddv)
+    }
+}
+
+impl<S: Float> std::ops::Div<AFloat2<S>> for AFloat2<S> {
+    type Output = AFloat2<S>;
+    fn div(self, rhs: Self) -> Self {
+        let a = self.v;
+        let b = rhs.v;
+        let v = a / b;
+        let dv = (self.dv * b - a * rhs.dv) / (b * b);
+        let two = S::one() + S::one();
+        let ddv = self.ddv / b - two * self.dv * rhs.dv / (b * b)
+            + two * a * rhs.dv * rhs.dv / (b * b * b)
+            - a * rhs.ddv / (b * b);
+        Self::new(v, dv, ddv)
+    }
+}
+
+macro_rules! scalar_binary_op {
+    ($op:ident, $method:ident) => {
+        impl<S: Float> std::ops::$op<f64> for AFloat2<S> {
+            type Output = AFloat2<S>;
+            fn $method(self, rhs: f64) -> Self {
+                self.$method(AFloat2::from(S::from(rhs).unwrap()))
+            }
+        }
+
+        impl<S: Float> std::ops::$op<AFloat2<S>> for f64 {
+            type Output = AFloat2<S>;
+            fn $method(self, rhs: AFloat2<S>) -> AFloat2<S> {
+                AFloat2::from(S::from(self).unwrap()).$method(rhs)
+            }
+        }
+    };
+}
+
+scalar_binary_op!(Add, add);
+scalar_binary_op!(Sub, sub);
+scalar_binary_op!(Mul, mul);
+scalar_binary_op!(Div, div);
+
+macro_rules! assign_op {
+    ($op:ident, $method:ident, $optoken:tt) => {
+        impl<S: Float> std::ops::$op<AFloat2<S>> for AFloat2<S> {
+            fn $method(&mut self, rhs: AFloat2<S>) {
+                let result = *self $optoken rhs;
+                *self = result;
+            }
+        }
+
+        impl<S: Float> std::ops::$op<f64> for AFloat2<S> {
+            fn $method(&mut self, rhs: f64) {
+                let result = *self $optoken rhs;
+                *self = result;
+            }
+        }
+    }
+}
+
+assign_op!(AddAssign, add_assign, +);
+assign_op!(SubAssign, sub_assign, -);
+assign_op!(MulAssign, mul_assign, *);
+assign_op!(DivAssign, div_assign, /);
+
+impl<S: Float> std::ops::Rem<AFloat2<S>> for AFloat2<S> {
+    type Output = AFloat2<S>;
+    fn rem(self, _rhs: Self) -> Self {
+        panic!("Operation '%' unsupported on AFloat2");
+    }
+}
+
+impl<S: Float> ToPrimitive for AFloat2<S> {
+    fn to_i64(&self) -> Option<i64> {
+        self.v.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.v.to_u64()
+    }
+}
+
+impl<S: Float> NumCast for AFloat2<S> {
+    fn from<T>(n: T) -> Option<Self>
+    where
+        T: ToPrimitive,
+    {
+        S::from(n).map(|n| Self::new(n, S::zero(), S::zero()))
+    }
+}
+
+impl<S: Float> Zero for AFloat2<S> {
+    fn zero() -> Self {
+        Self::new(S::zero(), S::zero(), S::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v.is_zero()
+    }
+}
+
+impl<S: Float> One for AFloat2<S> {
+    fn one() -> Self {
+        Self::new(S::one(), S::zero(), S::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.v.is_one()
+    }
+}
+
+impl<S: Float> Num for AFloat2<S> {
+    type FromStrRadixErr = S::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Ok(Self::new(S::from_str_radix(str, radix)?, S::zero(), S::zero()))
+    }
+}
+
+macro_rules! float_constant {
+    ($method:ident) => {
+        fn $method() -> Self {
+            Self::new(S::$method(), S::zero(), S::zero())
+        }
+    };
+}
+
+macro_rules! float_passthrough {
+    ($type:ty, $method:ident) => {
+        fn $method(self) -> $type {
+            self.v.$method()
+        }
+    };
+}
+
+macro_rules! float_unsupported {
+    ($type:ty, $method:ident $(, $arg_type:ty)*) => {
+        fn $method(self, $(_: $arg_type)*) -> $type {
+            panic!(concat!("Operation '", stringify!($method), "' unsupported on AFloat2"));
+        }
+    }
+}
+
+macro_rules! float_elemental {
+    ($method:ident, |$a:ident| $v:expr, |$av:ident| $dvda:expr, |$av2:ident| $ddvda:expr) => {
+        fn $method(self) -> Self {
+            let $a = self.v;
+            let v = $v;
+            let $av = self.v;
+            let dvda = $dvda;
+            let $av2 = self.v;
+            let ddvda = $ddvda;
+            self.unary(v, dvda, ddvda)
+        }
+    };
+}
+
+impl<S: Float> num::Float for AFloat2<S> {
+    float_constant!(nan);
+    float_constant!(infinity);
+    float_constant!(neg_infinity);
+    float_constant!(neg_zero);
+    float_constant!(min_value);
+    float_constant!(min_positive_value);
+    float_constant!(max_value);
+
+    float_passthrough!(bool, is_nan);
+    float_passthrough!(bool, is_infinite);
+    float_passthrough!(bool, is_finite);
+    float_passthrough!(bool, is_normal);
+    float_passthrough!(bool, is_sign_positive);
+    float_passthrough!(bool, is_sign_negative);
+
+    float_passthrough!(std::num::FpCategory, classify);
+
+    float_unsupported!(Self, floor);
+    float_unsupported!(Self, ceil);
+    float_unsupported!(Self, round);
+    float_unsupported!(Self, trunc);
+    float_unsupported!(Self, fract);
+    float_unsupported!(Self, signum);
+    float_unsupported!(Self, exp_m1);
+    float_unsupported!(Self, ln_1p);
+    float_unsupported!(Self, sinh);
+    float_unsupported!(Self, cosh);
+    float_unsupported!(Self, tanh);
+    float_unsupported!(Self, asinh);
+    float_unsupported!(Self, acosh);
+    float_unsupported!(Self, atanh);
+    float_unsupported!(Self, atan2, Self);
+
+    fn abs(self) -> Self {
+        // `|a|` is linear (away from the kink at `a == 0`), so the curvature just follows the
+        // sign of `a`: both tangents are scaled by `sign(a)`, with `+0.0` taking `+1` (the
+        // right-hand derivative). Unlike `Operation`'s `Abs` rule `|a + da| - |a|`, the tangent
+        // is `sign(a) * da`, also at the kink.
+        let sign = if self.v.is_sign_negative() {
+            -S::one()
+        } else {
+            S::one()
+        };
+        self.unary(self.v.abs(), sign, S::zero())
+    }
+
+    float_elemental!(exp, |a| a.exp(), |av| av.exp(), |av| av.exp());
+    float_elemental!(ln, |a| a.ln(), |av| S::one() / av, |av| -S::one()
+        / (av * av));
+    float_elemental!(sin, |a| a.sin(), |av| av.cos(), |av| -av.sin());
+    float_elemental!(cos, |a| a.cos(), |av| -av.sin(), |av| -av.cos());
+    float_elemental!(
+        tan,
+        |a| a.tan(),
+        |av| S::one() / (av.cos() * av.cos()),
+        |av| (S::one() + S::one()) * av.tan() / (av.cos() * av.cos())
+    );
+    float_elemental!(
+        asin,
+        |a| a.asin(),
+        |av| S::one() / (S::one() - av * av).sqrt(),
+        |av| av / (S::one() - av * av).powf(S::one() + S::one() / (S::one() + S::one()))
+    );
+    float_elemental!(
+        acos,
+        |a| a.acos(),
+        |av| -S::one() / (S::one() - av * av).sqrt(),
+        |av| -av / (S::one() - av * av).powf(S::one() + S::one() / (S::one() + S::one()))
+    );
+    float_elemental!(
+        atan,
+        |a| a.atan(),
+        |av| S::one() / (S::one() + av * av),
+        |av| -(S::one() + S::one()) * av / (S::one() + av * av).powi(2)
+    );
+
+    fn powf(self, other: Self) -> Self {
+        let x = self.v;
+        let y = other.v;
+        let dx = self.dv;
+        let dy = other.dv;
+        let ddx = self.ddv;
+        let ddy = other.ddv;
+        let two = S::one() + S::one();
+
+        let v = x.powf(y);
+
+        let rv1 = if dx != S::zero() {
+            y * x.powf(y - S::one()) * dx
+        } else {
+            S::zero()
+        };
+        let rv2 = if dy != S::zero() {
+            x.ln() * x.powf(y) * dy
+        } else {
+            S::zero()
+        };
+        let dv = rv1 + rv2;
+
+        // Hessian of `g(a, b) = a^b`: g_aa = b(b-1)a^(b-2), g_ab = a^(b-1)(1 + b*ln(a)),
+        // g_bb = a^b*ln(a)^2 -- the `ln(a)`-carrying terms are guarded the same way as `rv2`
+        // above, so a zero `dy`/`ddy` direction never drags in a NaN from `ln(a <= 0)`.
+        let needs_b_terms = dy != S::zero() || ddy != S::zero();
+
+        let g_aa = y * (y - S::one()) * x.powf(y - two);
+        let g_ab = if needs_b_terms {
+            x.powf(y - S::one()) * (S::one() + y * x.ln())
+        } else {
+            S::zero()
+        };
+        let g_bb = if needs_b_terms {
+            x.powf(y) * x.ln() * x.ln()
+        } else {
+            S::zero()
+        };
+        let g_a = y * x.powf(y - S::one());
+        let g_b = if needs_b_terms { x.ln() * x.powf(y) } else { S::zero() };
+
+        let ddv =
+            g_aa * dx * dx + two * g_ab * dx * dy + g_bb * dy * dy + g_a * ddx + g_b * ddy;
+
+        Self::new(v, dv, ddv)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        (self * a) + b
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.powf(<Self as NumCast>::from(n).unwrap())
+    }
+
+    fn sqrt(self) -> Self {
+        self.powf(<Self as NumCast>::from(0.5).unwrap())
+    }
+
+    fn exp2(self) -> Self {
+        <Self as NumCast>::from(2.0).unwrap().powf(self)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.log(<Self as NumCast>::from(2.0).unwrap())
+    }
+
+    fn log10(self) -> Self {
+        self.log(<Self as NumCast>::from(10.0).unwrap())
+    }
+
+    fn max(self, other: Self) -> Self {
+        <Self as NumCast>::from(0.5).unwrap() * (self + other + (self - other).abs())
+    }
+
+    fn min(self, other: Self) -> Self {
+        <Self as NumCast>::from(0.5).unwrap() * (self + other - (self - other).abs())
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).abs()
+    }
+
+    fn cbrt(self) -> Self {
+        self.powf(Self::one() / <Self as NumCast>::from(3.0).unwrap())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self.powi(2) + other.powi(2)).sqrt()
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.v.integer_decode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EPS: f64 = 1e-4;
+
+    /// Second directional derivative by central finite differences, for comparison
+    fn finite_diff_curvature<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> f64 {
+        (f(x + h) - 2.0 * f(x) + f(x - h)) / (h * h)
+    }
+
+    #[test]
+    fn afloat2_arithmetic_curvature() {
+        // f(x) = x*x*x -> f''(x) = 6x
+        let x = AFloat2::<f64>::seed(2.0);
+        let y = x * x * x;
+        assert!((y.ddvalue() - 12.0).abs() < EPS);
+    }
+
+    #[test]
+    #[allow(clippy::cognitive_complexity)]
+    fn afloat2_elemental_curvature_matches_finite_differences() {
+        macro_rules! test_case {
+            ($func:ident, $x0:expr) => {{
+                let x0: f64 = $x0;
+                let x = AFloat2::<f64>::seed(x0);
+                let y = x.$func();
+                let reference = finite_diff_curvature(|v| v.$func(), x0, 1e-3);
+                assert!(
+                    (y.ddvalue() - reference).abs() < 1e-2,
+                    "{}: got {}, expected {}",
+                    stringify!($func),
+                    y.ddvalue(),
+                    reference
+                );
+            }};
+        }
+        test_case!(exp, 0.5);
+        test_case!(sin, 0.5);
+        test_case!(cos, 0.5);
+        test_case!(ln, 1.5);
+        test_case!(asin, 0.3);
+        test_case!(acos, 0.3);
+        test_case!(atan, 0.5);
+    }
+
+    #[test]
+    fn afloat2_powf_matches_finite_differences() {
+        let x0 = 1.5_f64;
+        let x = AFloat2::<f64>::seed(x0);
+        let y = x.powf(AFloat2::from(3.0));
+        let reference = finite_diff_curvature(|v| v.powf(3.0), x0, 1e-3);
+        assert!((y.ddvalue() - reference).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,391 @@
+use super::*;
+use num::traits::{Num, NumCast, One, ToPrimitive, Zero};
+
+/// A dual number carrying `N` directional derivatives ("tangents") over a generic [`Float`]
+/// scalar, for batched tapeless forward-mode automatic differentiation
+///
+/// Generalizes [`ADoubleK`] (which is hardcoded to `f64`) to any [`Float`] scalar `S`, the same
+/// way [`AFloat`] generalizes [`ADouble`]. Seeding each independent variable with a distinct unit
+/// tangent via [`AFloatN::seed`] and evaluating the function once yields the whole
+/// directional-derivative block -- e.g. a full Jacobian row/block -- directly from the outputs'
+/// [`AFloatN::dvalue`] arrays, instead of one evaluation per input direction like plain [`AFloat`].
+#[derive(Clone, Copy, Debug)]
+pub struct AFloatN<S: Float, const N: usize> {
+    v: S,
+    dv: [S; N],
+}
+
+impl<S: Float, const N: usize> AFloatN<S, N> {
+    /// Create a variable from its zero-order value and tangent block
+    pub fn new(v: S, dv: [S; N]) -> Self {
+        Self { v, dv }
+    }
+
+    /// Create an independent variable seeding tangent direction `k` with `1`
+    pub fn seed(v: S, k: usize) -> Self {
+        let mut dv = [S::zero(); N];
+        dv[k] = S::one();
+        Self { v, dv }
+    }
+
+    /// Get the zero-order value
+    pub fn value(&self) -> S {
+        self.v
+    }
+
+    /// Get the tangent block
+    pub fn dvalue(&self) -> &[S; N] {
+        &self.dv
+    }
+
+    /// Apply a unary operation, scaling the tangent block by `dvda = df/da`
+    fn unary(self, v: S, dvda: S) -> Self {
+        let mut dv = self.dv;
+        for x in dv.iter_mut() {
+            *x = *x * dvda;
+        }
+        Self { v, dv }
+    }
+}
+
+impl<S: Float, const N: usize> From<S> for AFloatN<S, N> {
+    fn from(scalar: S) -> Self {
+        Self::new(scalar, [S::zero(); N])
+    }
+}
+
+impl<S: Float, const N: usize> std::cmp::PartialEq<AFloatN<S, N>> for AFloatN<S, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.v.eq(&other.v)
+    }
+}
+
+impl<S: Float, const N: usize> std::cmp::PartialOrd<AFloatN<S, N>> for AFloatN<S, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.v.partial_cmp(&other.v)
+    }
+}
+
+impl<S: Float, const N: usize> std::ops::Neg for AFloatN<S, N> {
+    type Output = AFloatN<S, N>;
+    fn neg(self) -> Self {
+        self.unary(-self.v, -S::one())
+    }
+}
+
+macro_rules! binary_op {
+    ($op:ident, $method:ident, |$av:ident, $bv:ident| $v:expr, |$ad:ident, $bd:ident| $dv:expr) => {
+        impl<S: Float, const N: usize> std::ops::$op<AFloatN<S, N>> for AFloatN<S, N> {
+            type Output = AFloatN<S, N>;
+            fn $method(self, rhs: Self) -> Self {
+                let $av = self.v;
+                let $bv = rhs.v;
+                let v = $v;
+                let mut dv = [S::zero(); N];
+                for i in 0..N {
+                    let $ad = self.dv[i];
+                    let $bd = rhs.dv[i];
+                    dv[i] = $dv;
+                }
+                Self::new(v, dv)
+            }
+        }
+
+        impl<S: Float, const N: usize> std::ops::$op<f64> for AFloatN<S, N> {
+            type Output = AFloatN<S, N>;
+            fn $method(self, rhs: f64) -> Self {
+                self.$method(AFloatN::from(S::from(rhs).unwrap()))
+            }
+        }
+
+        impl<S: Float, const N: usize> std::ops::$op<AFloatN<S, N>> for f64 {
+            type Output = AFloatN<S, N>;
+            fn $method(self, rhs: AFloatN<S, N>) -> AFloatN<S, N> {
+                AFloatN::from(S::from(self).unwrap()).$method(rhs)
+            }
+        }
+    };
+}
+
+binary_op!(Add, add, |av, bv| av + bv, |ad, bd| ad + bd);
+binary_op!(Sub, sub, |av, bv| av - bv, |ad, bd| ad - bd);
+binary_op!(Mul, mul, |av, bv| av * bv, |ad, bd| ad * bv + av * bd);
+binary_op!(Div, div, |av, bv| av / bv, |ad, bd| (ad * bv - av * bd)
+    / (bv * bv));
+
+macro_rules! assign_op {
+    ($op:ident, $method:ident, $optoken:tt) => {
+        impl<S: Float, const N: usize> std::ops::$op<AFloatN<S, N>> for AFloatN<S, N> {
+            fn $method(&mut self, rhs: AFloatN<S, N>) {
+                let result = *self $optoken rhs;
+                *self = result;
+            }
+        }
+
+        impl<S: Float, const N: usize> std::ops::$op<f64> for AFloatN<S, N> {
+            fn $method(&mut self, rhs: f64) {
+                let result = *self $optoken rhs;
+                *self = result;
+            }
+        }
+    }
+}
+
+assign_op!(AddAssign, add_assign, +);
+assign_op!(SubAssign, sub_assign, -);
+assign_op!(MulAssign, mul_assign, *);
+assign_op!(DivAssign, div_assign, /);
+
+impl<S: Float, const N: usize> std::ops::Rem<AFloatN<S, N>> for AFloatN<S, N> {
+    type Output = AFloatN<S, N>;
+    fn rem(self, _rhs: Self) -> Self {
+        panic!("Operation '%' unsupported on AFloatN");
+    }
+}
+
+impl<S: Float, const N: usize> ToPrimitive for AFloatN<S, N> {
+    fn to_i64(&self) -> Option<i64> {
+        self.v.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.v.to_u64()
+    }
+}
+
+impl<S: Float, const N: usize> NumCast for AFloatN<S, N> {
+    fn from<T>(n: T) -> Option<Self>
+    where
+        T: ToPrimitive,
+    {
+        S::from(n).map(|n| Self::new(n, [S::zero(); N]))
+    }
+}
+
+impl<S: Float, const N: usize> Zero for AFloatN<S, N> {
+    fn zero() -> Self {
+        Self::new(S::zero(), [S::zero(); N])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v.is_zero()
+    }
+}
+
+impl<S: Float, const N: usize> One for AFloatN<S, N> {
+    fn one() -> Self {
+        Self::new(S::one(), [S::zero(); N])
+    }
+
+    fn is_one(&self) -> bool {
+        self.v.is_one()
+    }
+}
+
+impl<S: Float, const N: usize> Num for AFloatN<S, N> {
+    type FromStrRadixErr = S::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Ok(Self::new(S::from_str_radix(str, radix)?, [S::zero(); N]))
+    }
+}
+
+macro_rules! float_constant {
+    ($method:ident) => {
+        fn $method() -> Self {
+            Self::new(S::$method(), [S::zero(); N])
+        }
+    };
+}
+
+macro_rules! float_passthrough {
+    ($type:ty, $method:ident) => {
+        fn $method(self) -> $type {
+            self.v.$method()
+        }
+    };
+}
+
+macro_rules! float_unsupported {
+    ($type:ty, $method:ident $(, $arg_type:ty)*) => {
+        fn $method(self, $(_: $arg_type)*) -> $type {
+            panic!(concat!("Operation '", stringify!($method), "' unsupported on AFloatN"));
+        }
+    }
+}
+
+macro_rules! float_elemental {
+    ($method:ident, |$a:ident| $v:expr, |$av:ident| $dvda:expr) => {
+        fn $method(self) -> Self {
+            let $a = self.v;
+            let v = $v;
+            let $av = self.v;
+            self.unary(v, $dvda)
+        }
+    };
+}
+
+impl<S: Float, const N: usize> num::Float for AFloatN<S, N> {
+    float_constant!(nan);
+    float_constant!(infinity);
+    float_constant!(neg_infinity);
+    float_constant!(neg_zero);
+    float_constant!(min_value);
+    float_constant!(min_positive_value);
+    float_constant!(max_value);
+
+    float_passthrough!(bool, is_nan);
+    float_passthrough!(bool, is_infinite);
+    float_passthrough!(bool, is_finite);
+    float_passthrough!(bool, is_normal);
+    float_passthrough!(bool, is_sign_positive);
+    float_passthrough!(bool, is_sign_negative);
+
+    float_passthrough!(std::num::FpCategory, classify);
+
+    float_unsupported!(Self, floor);
+    float_unsupported!(Self, ceil);
+    float_unsupported!(Self, round);
+    float_unsupported!(Self, trunc);
+    float_unsupported!(Self, fract);
+    float_unsupported!(Self, signum);
+    float_unsupported!(Self, exp_m1);
+    float_unsupported!(Self, ln_1p);
+    float_unsupported!(Self, sinh);
+    float_unsupported!(Self, cosh);
+    float_unsupported!(Self, tanh);
+    float_unsupported!(Self, asinh);
+    float_unsupported!(Self, acosh);
+    float_unsupported!(Self, atanh);
+    float_unsupported!(Self, atan2, Self);
+
+    fn abs(self) -> Self {
+        // Directional derivative of `|a|`, matching `Operation`'s convention of treating a kink
+        // at `a == 0` as differentiable from the right
+        let mut dv = self.dv;
+        for x in dv.iter_mut() {
+            *x = (self.v + *x).abs() - self.v.abs();
+        }
+        Self::new(self.v.abs(), dv)
+    }
+
+    float_elemental!(exp, |a| a.exp(), |av| av.exp());
+    float_elemental!(ln, |a| a.ln(), |av| S::one() / av);
+    float_elemental!(sin, |a| a.sin(), |av| av.cos());
+    float_elemental!(cos, |a| a.cos(), |av| -av.sin());
+    float_elemental!(tan, |a| a.tan(), |av| S::one() / (av.cos() * av.cos()));
+    float_elemental!(asin, |a| a.asin(), |av| S::one()
+        / (S::one() - av * av).sqrt());
+    float_elemental!(acos, |a| a.acos(), |av| -S::one()
+        / (S::one() - av * av).sqrt());
+    float_elemental!(atan, |a| a.atan(), |av| S::one() / (S::one() + av * av));
+
+    fn powf(self, other: Self) -> Self {
+        let x = self.v;
+        let y = other.v;
+        let v = x.powf(y);
+
+        let mut dv = [S::zero(); N];
+        for i in 0..N {
+            let dx = self.dv[i];
+            let dy = other.dv[i];
+            let rv1 = if dx != S::zero() {
+                y * x.powf(y - S::one()) * dx
+            } else {
+                S::zero()
+            };
+            let rv2 = if dy != S::zero() {
+                x.ln() * x.powf(y) * dy
+            } else {
+                S::zero()
+            };
+            dv[i] = rv1 + rv2;
+        }
+        Self::new(v, dv)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        (self * a) + b
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.powf(<Self as NumCast>::from(n).unwrap())
+    }
+
+    fn sqrt(self) -> Self {
+        self.powf(<Self as NumCast>::from(0.5).unwrap())
+    }
+
+    fn exp2(self) -> Self {
+        <Self as NumCast>::from(2.0).unwrap().powf(self)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.log(<Self as NumCast>::from(2.0).unwrap())
+    }
+
+    fn log10(self) -> Self {
+        self.log(<Self as NumCast>::from(10.0).unwrap())
+    }
+
+    fn max(self, other: Self) -> Self {
+        <Self as NumCast>::from(0.5).unwrap() * (self + other + (self - other).abs())
+    }
+
+    fn min(self, other: Self) -> Self {
+        <Self as NumCast>::from(0.5).unwrap() * (self + other - (self - other).abs())
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).abs()
+    }
+
+    fn cbrt(self) -> Self {
+        self.powf(Self::one() / <Self as NumCast>::from(3.0).unwrap())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self.powi(2) + other.powi(2)).sqrt()
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.v.integer_decode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EPS: f64 = 1e-5;
+
+    #[test]
+    fn afloatn_arithmetic() {
+        let x = AFloatN::<f64, 2>::new(2.0, [1.0, 0.0]);
+        let y = AFloatN::<f64, 2>::new(3.0, [0.0, 1.0]);
+        let z = x * y + x;
+
+        assert!((z.value() - 8.0).abs() < EPS);
+        assert!((z.dvalue()[0] - 4.0).abs() < EPS);
+        assert!((z.dvalue()[1] - 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn afloatn_elemental() {
+        let x = AFloatN::<f64, 1>::seed(0.5, 0);
+        let y = x.sin();
+        assert!((y.value() - 0.5_f64.sin()).abs() < EPS);
+        assert!((y.dvalue()[0] - 0.5_f64.cos()).abs() < EPS);
+    }
+}
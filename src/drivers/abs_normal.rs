@@ -219,6 +219,69 @@ macro_rules! anf_matrix {
                 result
             }
 
+            /// Accumulating variant of [`mul_right`](Self::mul_right)
+            ///
+            /// Writes each forward sweep directly into the matching column of `out` instead of
+            /// allocating a fresh result matrix, either overwriting (`accumulate = false`) or
+            /// adding (`accumulate = true`), so callers such as `abs_normal` sweep into a
+            /// preallocated buffer and repeated applications can reuse it.
+            pub fn mul_right_into<R: Dim, C: Dim, S>(
+                &self,
+                rhs: &Matrix<f64, R, C, S>,
+                out: &mut DMatrix<f64>,
+                accumulate: bool,
+            ) where
+                S: nalgebra::storage::Storage<f64, R, C>,
+            {
+                assert_eq!(self.ncols(), rhs.nrows());
+                assert_eq!(out.nrows(), self.nrows());
+                assert_eq!(out.ncols(), rhs.ncols());
+                for j in 0..rhs.ncols() {
+                    let mut dx = DVector::zeros(rhs.nrows());
+                    for i in 0..rhs.nrows() {
+                        dx[i] = rhs[(i, j)];
+                    }
+                    let dy = self.first_order_forward(&dx);
+                    let mut col = out.column_mut(j);
+                    if accumulate {
+                        col += &dy;
+                    } else {
+                        col.copy_from(&dy);
+                    }
+                }
+            }
+
+            /// Accumulating variant of [`mul_left`](Self::mul_left)
+            ///
+            /// Writes each reverse sweep directly into the matching row of `out` instead of
+            /// allocating a fresh result matrix, either overwriting (`accumulate = false`) or
+            /// adding (`accumulate = true`).
+            pub fn mul_left_into<R: Dim, C: Dim, S>(
+                &self,
+                lhs: &Matrix<f64, R, C, S>,
+                out: &mut DMatrix<f64>,
+                accumulate: bool,
+            ) where
+                S: nalgebra::storage::Storage<f64, R, C>,
+            {
+                assert_eq!(lhs.ncols(), self.nrows());
+                assert_eq!(out.nrows(), lhs.nrows());
+                assert_eq!(out.ncols(), self.ncols());
+                for i in 0..lhs.nrows() {
+                    let mut ybar = DVector::zeros(lhs.ncols());
+                    for j in 0..lhs.ncols() {
+                        ybar[j] = lhs[(i, j)];
+                    }
+                    let xbar = self.first_order_reverse(&ybar);
+                    let mut row = out.row_mut(i);
+                    if accumulate {
+                        row += xbar.transpose();
+                    } else {
+                        row.copy_from(&xbar.transpose());
+                    }
+                }
+            }
+
             pub fn row(&self, i: usize) -> DMatrix<f64> {
                 let mut ind = DMatrix::zeros(1, self.num_deps());
                 ind[(0, i)] = 1.0;
@@ -292,10 +355,126 @@ pub struct AbsNormalForm {
     pub ymat: DMatrix<f64>,
 }
 
+impl AbsNormalForm {
+    /// Evaluate the piecewise-linear model at a perturbation `dx`
+    ///
+    /// Returns the switching vector `z` and the perturbed dependents `dy`. Since `lmat` is
+    /// strictly lower triangular, `z` solves `z = a + Z·dx + L·|z|` by forward substitution:
+    /// row `i` only depends on `z[0..i]`, which are already known once we reach row `i`.
+    pub fn eval(&self, dx: &DVector<f64>) -> (DVector<f64>, DVector<f64>) {
+        assert_eq!(dx.nrows(), self.zmat.ncols());
+        let s = self.a.nrows();
+
+        let r = &self.a + &self.zmat * dx;
+        let mut z = DVector::zeros(s);
+        for i in 0..s {
+            let mut row_sum = 0.0;
+            for j in 0..i {
+                row_sum += self.lmat[(i, j)] * z[j].abs();
+            }
+            z[i] = r[i] + row_sum;
+        }
+
+        let dy = &self.b + &self.jmat * dx + &self.ymat * z.abs();
+
+        (z, dy)
+    }
+
+    /// Enumerate the (generators of the) Clarke generalized Jacobian at `dx = 0`
+    ///
+    /// For every switching variable that is currently exactly zero, both signs `±1` are
+    /// admissible; variables away from the kink keep their observed sign. Each admissible
+    /// signature `sigma` yields one limiting Jacobian `J + Y·Σ·(I - L·Σ)^{-1}·Z`, and
+    /// identical matrices (e.g. from switches that do not affect the result) are deduplicated.
+    pub fn generalized_jacobians(&self) -> Vec<DMatrix<f64>> {
+        let s = self.a.nrows();
+        let n = self.zmat.ncols();
+        let (z0, _) = self.eval(&DVector::zeros(n));
+
+        let free: Vec<usize> = (0..s).filter(|&i| z0[i] == 0.0).collect();
+        let fixed_sigma: Vec<f64> = (0..s)
+            .map(|i| if z0[i] > 0.0 { 1.0 } else { -1.0 })
+            .collect();
+
+        let mut jacobians = Vec::new();
+        let combinations = 1usize << free.len();
+        for combo in 0..combinations {
+            let mut sigma = DVector::from_vec(fixed_sigma.clone());
+            for (bit, &idx) in free.iter().enumerate() {
+                sigma[idx] = if (combo >> bit) & 1 == 0 { -1.0 } else { 1.0 };
+            }
+
+            let az = forward_solve_lower(&self.lmat, &sigma, &self.zmat);
+            let mut sig_az = az;
+            for i in 0..s {
+                let mut row = sig_az.row(i).clone_owned();
+                row *= sigma[i];
+                sig_az.row_mut(i).copy_from(&row);
+            }
+            let jac = &self.jmat + &self.ymat * sig_az;
+
+            if !jacobians.iter().any(|existing| *existing == jac) {
+                jacobians.push(jac);
+            }
+        }
+        jacobians
+    }
+}
+
+/// Solve `(I - L*diag(sigma))*out = rhs` for `out` by forward substitution
+///
+/// `L` is strictly lower triangular, so `I - L*diag(sigma)` is unit lower triangular and the
+/// system is solvable row by row without factorization.
+pub(crate) fn forward_solve_lower(
+    lmat: &DMatrix<f64>,
+    sigma: &DVector<f64>,
+    rhs: &DMatrix<f64>,
+) -> DMatrix<f64> {
+    let s = lmat.nrows();
+    let mut out = rhs.clone();
+    for i in 0..s {
+        let mut row = out.row(i).clone_owned();
+        for j in 0..i {
+            row += lmat[(i, j)] * sigma[j] * out.row(j);
+        }
+        out.row_mut(i).copy_from(&row);
+    }
+    out
+}
+
+/// Solve `out*(I - L*diag(sigma)) = rhs` for `out` by back substitution over columns
+///
+/// Transposing turns the unit lower triangular `I - L*diag(sigma)` into a unit upper triangular
+/// system, so column `j` (which depends on columns `k > j`, already known once we reach it
+/// descending from the last column) can be resolved directly without factorization.
+pub(crate) fn back_solve_lower(
+    lmat: &DMatrix<f64>,
+    sigma: &DVector<f64>,
+    rhs: &DMatrix<f64>,
+) -> DMatrix<f64> {
+    let s = lmat.nrows();
+    let mut out = rhs.clone();
+    for j in (0..s).rev() {
+        let mut col = out.column(j).clone_owned();
+        for k in (j + 1)..s {
+            col += sigma[j] * lmat[(k, j)] * out.column(k);
+        }
+        out.column_mut(j).copy_from(&col);
+    }
+    out
+}
+
 /// Derive a dense Abs-Normal form from a function
-#[allow(clippy::many_single_char_names)]
 pub fn abs_normal(func: &dyn Function, x: &DVector<f64>) -> AbsNormalForm {
-    let tape = func.tape(x);
+    abs_normal_from_tape(func.tape(x))
+}
+
+/// Derive a dense Abs-Normal form directly from an already-evaluated tape
+///
+/// Shared by [`abs_normal`] (which first re-tapes `func` at `x`) and [`TapeAbsNormalExt::abs_normal`]
+/// (which works from a tape that already exists, with no [`Function`] required).
+#[allow(clippy::many_single_char_names)]
+pub fn abs_normal_from_tape(tape: Box<dyn Tape<f64>>) -> AbsNormalForm {
     let abs_tape = AbsNormalTape::new(tape);
     let n = abs_tape.n();
     let m = abs_tape.m();
@@ -306,25 +485,31 @@ pub fn abs_normal(func: &dyn Function, x: &DVector<f64>) -> AbsNormalForm {
     let j_tape = AbsNormalJ::new(&abs_tape);
     let y_tape = AbsNormalY::new(&abs_tape);
 
-    let zmat = if n < s {
-        z_tape.mul_right(&DMatrix::identity(n, n))
+    // Each matrix is swept in-place into a preallocated buffer instead of allocating a fresh
+    // result per `mul_right`/`mul_left` call.
+    let mut zmat = DMatrix::zeros(s, n);
+    if n < s {
+        z_tape.mul_right_into(&DMatrix::identity(n, n), &mut zmat, false);
     } else {
-        z_tape.mul_left(&DMatrix::identity(s, s))
-    };
+        z_tape.mul_left_into(&DMatrix::identity(s, s), &mut zmat, false);
+    }
 
-    let lmat = l_tape.mul_left(&DMatrix::identity(s, s));
+    let mut lmat = DMatrix::zeros(s, s);
+    l_tape.mul_left_into(&DMatrix::identity(s, s), &mut lmat, false);
 
-    let jmat = if n < m {
-        j_tape.mul_right(&DMatrix::identity(n, n))
+    let mut jmat = DMatrix::zeros(m, n);
+    if n < m {
+        j_tape.mul_right_into(&DMatrix::identity(n, n), &mut jmat, false);
     } else {
-        j_tape.mul_left(&DMatrix::identity(m, m))
-    };
+        j_tape.mul_left_into(&DMatrix::identity(m, m), &mut jmat, false);
+    }
 
-    let ymat = if s < m {
-        y_tape.mul_right(&DMatrix::identity(s, s))
+    let mut ymat = DMatrix::zeros(m, s);
+    if s < m {
+        y_tape.mul_right_into(&DMatrix::identity(s, s), &mut ymat, false);
     } else {
-        y_tape.mul_left(&DMatrix::identity(m, m))
-    };
+        y_tape.mul_left_into(&DMatrix::identity(m, m), &mut ymat, false);
+    }
 
     let z = abs_tape.z();
     let z_abs = z.abs();
@@ -341,6 +526,36 @@ pub fn abs_normal(func: &dyn Function, x: &DVector<f64>) -> AbsNormalForm {
     }
 }
 
+/// Extension trait exposing [`abs_normal`] directly on a [`Function`]
+///
+/// Every nonsmooth primitive (`abs`, `min`, `max`) lowers to an `Abs` op on the tape, in tape
+/// order, so this works for any `Function` without hand-written decompositions like
+/// `halfpipe_anf`.
+pub trait FunctionAbsNormalExt: Function {
+    /// Derive the dense Abs-Normal Form of this function at `x`
+    fn abs_normal_form(&self, x: &DVector<f64>) -> AbsNormalForm {
+        abs_normal(self, x)
+    }
+}
+
+impl<T: Function + ?Sized> FunctionAbsNormalExt for T {}
+
+/// Extension trait exposing the dense Abs-Normal Form directly on an already-evaluated tape
+///
+/// Unlike [`FunctionAbsNormalExt`], this needs no [`Function`] or evaluation point around --
+/// only the tape itself, e.g. a [`SerializedTape`] loaded from disk. Takes a snapshot of `self`
+/// via [`SerializedTape::from_tape`] rather than consuming `self` directly, since [`AbsNormalTape`]
+/// needs to own its inner tape.
+pub trait TapeAbsNormalExt: Tape<f64> {
+    /// Derive the dense Abs-Normal Form of this tape at its currently stored point
+    fn abs_normal(&self) -> AbsNormalForm {
+        let snapshot: Box<dyn Tape<f64>> = Box::new(SerializedTape::from_tape(self));
+        abs_normal_from_tape(snapshot)
+    }
+}
+
+impl<T: Tape<f64> + ?Sized> TapeAbsNormalExt for T {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -431,6 +646,104 @@ mod tests {
         assert!((anf.ymat[(0, 0)] - 1.0).abs() < std::f64::EPSILON);
     }
 
+    #[test]
+    fn abs_normal_form_via_function_ext() {
+        let x = DVector::from_vec(vec![1.0, 2.0]);
+        let func = adv_fn_obj!(halfpipe);
+        assert_eq!(func.abs_normal_form(&x), abs_normal(&func, &x));
+    }
+
+    /// `TapeAbsNormalExt::abs_normal` works directly from a tape, with no `Function` in hand,
+    /// and matches the `Function`-based `abs_normal`
+    #[test]
+    fn abs_normal_form_via_tape_ext() {
+        let x = DVector::from_vec(vec![1.0, 2.0]);
+        let func = adv_fn_obj!(halfpipe);
+        let tape = func.tape(&x);
+        assert_eq!(tape.abs_normal(), abs_normal(&func, &x));
+    }
+
+    #[test]
+    fn mul_into_matches_mul() {
+        let x = DVector::from_vec(vec![1.0, 2.0]);
+        let tape = adv_fn_obj!(halfpipe).tape(&x);
+        let abs_tape = AbsNormalTape::new(tape);
+        let z_tape = AbsNormalZ::new(&abs_tape);
+
+        let identity = DMatrix::<f64>::identity(2, 2);
+        let reference = z_tape.mul_right(&identity);
+
+        let mut out = DMatrix::zeros(2, 2);
+        z_tape.mul_right_into(&identity, &mut out, false);
+        assert_eq!(out, reference);
+
+        // Accumulating twice into a zeroed buffer doubles the result
+        let mut accumulated = DMatrix::zeros(2, 2);
+        z_tape.mul_right_into(&identity, &mut accumulated, true);
+        z_tape.mul_right_into(&identity, &mut accumulated, true);
+        assert_eq!(accumulated, &reference * 2.0);
+    }
+
+    #[test]
+    fn abs_normal_form_eval() {
+        for x1 in (0..10).map(|i| (i as f64) * 0.5) {
+            for x2 in (0..10).map(|i| (i as f64) * 0.5) {
+                for dx1 in (0..4).map(|i| (i as f64) * 0.5 - 1.0) {
+                    for dx2 in (0..4).map(|i| (i as f64) * 0.5 - 1.0) {
+                        let x = DVector::from_vec(vec![x1, x2]);
+                        let dx = DVector::from_vec(vec![dx1, dx2]);
+                        let anf = abs_normal(&adv_fn_obj!(halfpipe), &x);
+                        let (z, dy) = anf.eval(&dx);
+
+                        // Fixed-point iteration to convergence, as a reference for the
+                        // closed-form forward substitution above.
+                        let dzt = &anf.a + &anf.zmat * &dx;
+                        let mut z_ref = dzt.clone();
+                        loop {
+                            let next = &dzt + &anf.lmat * z_ref.abs();
+                            if next == z_ref {
+                                break;
+                            }
+                            z_ref = next;
+                        }
+                        let dy_ref = &anf.b + &anf.jmat * &dx + &anf.ymat * z_ref.abs();
+
+                        assert_eq!(z, z_ref);
+                        assert_eq!(dy, dy_ref);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generalized_jacobians_at_kink() {
+        // At x = (0, 0), both of halfpipe's switches (`z0 = x0` and `z1 = x1^2 - max(x0, 0)`)
+        // sit exactly on the kink, so all four signatures are enumerated; they yield only two
+        // distinct Jacobians, `(0, 0)` and `(-1, 0)`.
+        let x = DVector::from_vec(vec![0.0, 0.0]);
+        let anf = abs_normal(&adv_fn_obj!(halfpipe), &x);
+        let jacobians = anf.generalized_jacobians();
+        assert_eq!(jacobians.len(), 2);
+
+        for jac in &jacobians {
+            assert_eq!(jac.nrows(), 1);
+            assert_eq!(jac.ncols(), 2);
+        }
+    }
+
+    #[test]
+    fn generalized_jacobians_away_from_kink() {
+        let x = DVector::from_vec(vec![2.0, 3.0]);
+        let anf = abs_normal(&adv_fn_obj!(halfpipe), &x);
+        let jacobians = anf.generalized_jacobians();
+        assert_eq!(jacobians.len(), 1);
+
+        let dx = DVector::from_vec(vec![0.0, 0.0]);
+        let jac_ref = generalized_jacobian(&adv_fn_obj!(halfpipe), &x, &dx, &[0], None);
+        assert_eq!(jacobians[0], jac_ref.homogenous);
+    }
+
     #[test]
     #[allow(clippy::cognitive_complexity)]
     fn abs_decompose() {
@@ -0,0 +1,107 @@
+use super::*;
+
+/// Result of an [`abs_normal_newton`] run
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbsNormalNewtonResult {
+    /// Root (or best estimate) found by re-linearizing and solving repeatedly
+    pub x: DVector<f64>,
+    /// Signature vector of the switching variables at the last linearization
+    pub sigma: DVector<f64>,
+    /// Number of outer Newton steps taken
+    pub iterations: usize,
+    /// Whether `||F(x)||` dropped below `tol` before `max_iter` outer steps were exhausted
+    pub converged: bool,
+}
+
+/// Solve `F(x) = 0` for a square, possibly nonsmooth `func` by repeatedly re-linearizing its
+/// Abs-Normal Form
+///
+/// Each outer step builds a fresh [`AbsNormalForm`] at the current `x` and drives it to zero
+/// with [`pl_solve`]'s inner signature iteration (bounded by `max_sign_flips`), then takes the
+/// resulting step and re-tapes at the new point. A single [`pl_solve`] call only solves the
+/// *local* piecewise-linear model exactly; since `func` itself may be genuinely nonlinear away
+/// from the current point, outer re-linearization steps are still needed, mirroring how plain
+/// Newton's method re-derives its linear model at every iterate. Stops once `||F(x)||` drops
+/// below `tol`, `max_iter` outer steps are exhausted, or an inner signature iteration fails to
+/// stabilize within `max_sign_flips`.
+#[allow(clippy::many_single_char_names)]
+pub fn abs_normal_newton(
+    func: &dyn Function,
+    x0: &DVector<f64>,
+    tol: f64,
+    max_iter: usize,
+    max_sign_flips: usize,
+) -> AbsNormalNewtonResult {
+    assert_eq!(func.n(), func.m());
+
+    let mut x = x0.clone();
+    let mut sigma = DVector::zeros(func.tape(&x).num_abs());
+    let mut converged = func.eval_float(x.clone()).norm() <= tol;
+    let mut iterations = 0;
+
+    while !converged && iterations < max_iter {
+        let step = pl_solve(func, &x, max_sign_flips);
+        sigma = step.sigma;
+        if !step.converged {
+            break;
+        }
+
+        x += &step.dx;
+        iterations += 1;
+        converged = func.eval_float(x.clone()).norm() <= tol;
+    }
+
+    AbsNormalNewtonResult {
+        x,
+        sigma,
+        iterations,
+        converged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    adv_fn! {
+        fn newton_quadratic(x: [[1]]) -> [[1]] {
+            adv_dvec![x[0] * x[0] - 2.0]
+        }
+    }
+
+    #[test]
+    fn abs_normal_newton_quadratic() {
+        let result = abs_normal_newton(
+            &adv_fn_obj!(newton_quadratic),
+            &DVector::from_vec(vec![1.0]),
+            1e-10,
+            20,
+            10,
+        );
+        assert!(result.converged);
+        assert!((result.x[0] - 2.0_f64.sqrt()).abs() < 1e-8);
+        // `newton_quadratic` has no abs ops, so its inner `pl_solve` calls must still run the QR
+        // solve and actually move `x` away from the start point -- guards against a regression
+        // where an `s == 0` tape short-circuits the solve and `x` never leaves `x0`.
+        assert!(result.iterations > 0);
+    }
+
+    adv_fn! {
+        fn newton_abs_root(x: [[1]]) -> [[1]] {
+            adv_dvec![x[0].abs() - 1.0]
+        }
+    }
+
+    #[test]
+    fn abs_normal_newton_abs_root() {
+        let result = abs_normal_newton(
+            &adv_fn_obj!(newton_abs_root),
+            &DVector::from_vec(vec![-5.0]),
+            1e-10,
+            20,
+            10,
+        );
+        assert!(result.converged);
+        assert!((result.x[0].abs() - 1.0).abs() < 1e-8);
+    }
+}
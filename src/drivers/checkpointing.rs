@@ -83,29 +83,23 @@ fn schedule(c: usize, r: usize) -> impl Iterator<Item = usize> {
     })
 }
 
-/// Generate a sequence and walk it in reverse using limited memory
-pub fn reverse_sequence<T, FW, RV, R, ID>(
-    x: T,
+/// Walk the tail of a partially filled checkpoint buffer in reverse, recomputing intermediate
+/// states from the nearest checkpoint via `forward` as dictated by the binomial schedule
+fn drain_checkpoints<T, FW, RV, R, ID>(
+    mut checkpoints: VecDeque<(usize, T)>,
     nsteps: usize,
     ncheckpoints: usize,
-    forward: FW,
+    mut forward: FW,
     reverse: RV,
     identity: ID,
 ) -> R
 where
     T: Clone + Debug,
     R: Debug,
-    FW: Fn(T) -> T,
+    FW: FnMut(T) -> T,
     RV: Fn(T, R) -> R,
     ID: Fn(T) -> R,
 {
-    // We need at least 2 checkpoints: One for the beginning of the sequence and one for the end.
-    assert!(ncheckpoints >= 2);
-
-    // Store x at index 0 as the first checkpoint
-    let mut checkpoints: VecDeque<(usize, T)> = VecDeque::with_capacity(ncheckpoints);
-    checkpoints.push_back((0, x));
-
     // Length of the not-yet reversed sequence
     let mut r = nsteps + 1;
 
@@ -149,6 +143,97 @@ where
     result.unwrap()
 }
 
+/// Generate a sequence and walk it in reverse using limited memory
+pub fn reverse_sequence<T, FW, RV, R, ID>(
+    x: T,
+    nsteps: usize,
+    ncheckpoints: usize,
+    forward: FW,
+    reverse: RV,
+    identity: ID,
+) -> R
+where
+    T: Clone + Debug,
+    R: Debug,
+    FW: Fn(T) -> T,
+    RV: Fn(T, R) -> R,
+    ID: Fn(T) -> R,
+{
+    // We need at least 2 checkpoints: One for the beginning of the sequence and one for the end.
+    assert!(ncheckpoints >= 2);
+
+    // Store x at index 0 as the first checkpoint
+    let mut checkpoints: VecDeque<(usize, T)> = VecDeque::with_capacity(ncheckpoints);
+    checkpoints.push_back((0, x));
+
+    drain_checkpoints(checkpoints, nsteps, ncheckpoints, forward, reverse, identity)
+}
+
+/// Evict the interior checkpoint whose removal least increases the worst-case recomputation
+/// distance, keeping the remaining checkpoints roughly geometrically spaced
+fn evict_checkpoint<T>(checkpoints: &mut VecDeque<(usize, T)>) {
+    let len = checkpoints.len();
+    assert!(len >= 3, "need an interior checkpoint to evict");
+
+    let mut evict = 1;
+    let mut smallest_merged_gap = usize::MAX;
+    for i in 1..len - 1 {
+        let merged_gap = checkpoints[i + 1].0 - checkpoints[i - 1].0;
+        if merged_gap < smallest_merged_gap {
+            smallest_merged_gap = merged_gap;
+            evict = i;
+        }
+    }
+    checkpoints.remove(evict);
+}
+
+/// Generate a sequence of unknown length online and walk it in reverse using limited memory
+///
+/// Unlike [`reverse_sequence`], `reverse_stream` does not require `nsteps` up front. Instead,
+/// `step` is called repeatedly with the current state and returns the next one, or `None` once
+/// the sequence has ended. Checkpoints are recorded as the sequence is consumed; once more than
+/// `ncheckpoints` have accumulated, [`evict_checkpoint`] drops the one whose removal least
+/// increases the worst-case recomputation distance. Once `step` signals the end, the stream
+/// reverses exactly as [`reverse_sequence`] does, recomputing any gaps left by evicted
+/// checkpoints via the separate, replayable `forward` callback rather than `step` itself --
+/// `step` is a one-shot consuming stream (e.g. an adaptive solver advancing its own internal
+/// state) and may have nothing left to return by the time a gap needs recomputing, whereas
+/// `forward` is assumed pure and callable as many times as recomputation requires.
+pub fn reverse_stream<T, FW, ST, RV, R, ID>(
+    x: T,
+    ncheckpoints: usize,
+    mut step: ST,
+    forward: FW,
+    reverse: RV,
+    identity: ID,
+) -> R
+where
+    T: Clone + Debug,
+    R: Debug,
+    ST: FnMut(&T) -> Option<T>,
+    FW: FnMut(T) -> T,
+    RV: Fn(T, R) -> R,
+    ID: Fn(T) -> R,
+{
+    // We need at least 2 checkpoints: One for the beginning of the sequence and one for the end.
+    assert!(ncheckpoints >= 2);
+
+    // Store x at index 0 as the first checkpoint
+    let mut checkpoints: VecDeque<(usize, T)> = VecDeque::with_capacity(ncheckpoints);
+    checkpoints.push_back((0, x));
+
+    let mut nsteps = 0;
+    while let Some(next) = step(&checkpoints.back().unwrap().1) {
+        nsteps += 1;
+        checkpoints.push_back((nsteps, next));
+        if checkpoints.len() > ncheckpoints {
+            evict_checkpoint(&mut checkpoints);
+        }
+    }
+
+    drain_checkpoints(checkpoints, nsteps, ncheckpoints, forward, reverse, identity)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,4 +288,72 @@ mod tests {
         let result = reverse_sequence(0, r, 9, |x| x + 1, |x, y| x + y, |x| x);
         assert_eq!(result, reference);
     }
+
+    #[test]
+    fn stream_reverse_gauss_sum() {
+        let r = 37;
+        let reference = r * (r + 1) / 2;
+        let mut remaining = r;
+        let result = reverse_stream(
+            0,
+            9,
+            |x| {
+                if remaining > 0 {
+                    remaining -= 1;
+                    Some(x + 1)
+                } else {
+                    None
+                }
+            },
+            |x| x + 1,
+            |x, y| x + y,
+            |x| x,
+        );
+        assert_eq!(result, reference);
+    }
+
+    /// With few checkpoints relative to the sequence length, evictions are unavoidable, so
+    /// reversal must recompute some gaps. That recomputation has to go through the separate
+    /// `forward` callback, not `step` -- `step` is asserted to be called exactly once per
+    /// produced element plus once to observe the end of the stream, so any extra call (which
+    /// would panic on a one-shot stream in real use) would show up as a mismatched count here.
+    #[test]
+    fn stream_reverse_recomputes_via_forward_not_step() {
+        let r = 50;
+        let reference = r * (r + 1) / 2;
+        let mut remaining = r;
+        let mut step_calls = 0;
+        let mut forward_calls = 0;
+        let result = reverse_stream(
+            0,
+            3,
+            |x| {
+                step_calls += 1;
+                if remaining > 0 {
+                    remaining -= 1;
+                    Some(x + 1)
+                } else {
+                    None
+                }
+            },
+            |x| {
+                forward_calls += 1;
+                x + 1
+            },
+            |x, y| x + y,
+            |x| x,
+        );
+        assert_eq!(result, reference);
+        assert_eq!(step_calls, r + 1);
+        assert!(forward_calls > 0);
+    }
+
+    #[test]
+    fn evict_checkpoint_keeps_geometric_spacing() {
+        let mut checkpoints: VecDeque<(usize, ())> =
+            vec![0, 1, 2, 3, 10].into_iter().map(|i| (i, ())).collect();
+        evict_checkpoint(&mut checkpoints);
+        let indices: Vec<usize> = checkpoints.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, vec![0, 2, 3, 10]);
+    }
 }
@@ -1,3 +1,4 @@
+use super::abs_normal::back_solve_lower;
 use super::*;
 use std::iter::Iterator;
 
@@ -36,7 +37,25 @@ pub fn generalized_jacobian(
     generalized_jacobian_tape(tape, dx, sign_bits, next)
 }
 
+/// Extension trait exposing [`generalized_jacobian`] directly on a [`Function`]
+pub trait FunctionGeneralizedJacobianExt: Function {
+    /// Derive a single element of the Clarke generalized Jacobian of this function at `x` in
+    /// direction `dx`, resolving exact kinks to `-1` (see [`generalized_jacobian`])
+    fn generalized_jacobian(&self, x: &DVector<f64>, dx: &DVector<f64>) -> GeneralizedJacobian {
+        generalized_jacobian(self, x, dx, &[0], None)
+    }
+}
+
+impl<T: Function + ?Sized> FunctionGeneralizedJacobianExt for T {}
+
 /// Derive the Generalized Jacobian of a tape
+///
+/// `L` is strictly lower triangular (switching variable `i` can only depend on switching
+/// variables `j < i`), so both fixed points this needs -- `Δz = Δz_t + L·|Δz|` and
+/// `YΣA = YΣ + YΣA·L·Σ` -- are triangular systems solved exactly in one substitution pass each,
+/// rather than iterated up to `s` times relying on float equality to detect convergence. See
+/// [`generalized_jacobian_tape_iterative`] for the older bounded-iteration scheme kept around for
+/// cross-validation.
 #[allow(clippy::many_single_char_names)]
 pub fn generalized_jacobian_tape(
     tape: Box<dyn Tape<f64>>,
@@ -63,6 +82,95 @@ pub fn generalized_jacobian_tape(
     let a = &z - l_tape.mul_right(&z_abs);
     let b = -y_tape.mul_right(&z_abs);
 
+    // Materialize L once; every substitution below reads it by entry rather than via the tape
+    let l_mat = l_tape.mul_left(&DMatrix::<f64>::identity(s, s));
+
+    // Calculate Δz by forward substitution: row i only depends on Δz[0..i], already finalized by
+    // the time row i is reached. Fold σ and the multiplicity of degenerate (exactly zero) kinks
+    // into the same pass.
+    let dzt = &a + z_tape.mul_right(&dx);
+    let mut dz = DVector::zeros(s);
+    let mut sigma = DVector::zeros(s);
+    let mut multiplicity = 0_usize;
+    let mut signs = bit_iter(sign_bits);
+    for i in 0..s {
+        let mut row_sum = 0.0;
+        for j in 0..i {
+            row_sum += l_mat[(i, j)] * dz[j].abs();
+        }
+        dz[i] = dzt[i] + row_sum;
+        if dz[i] < 0.0 {
+            sigma[i] = -1.0;
+        } else if dz[i] > 0.0 {
+            sigma[i] = 1.0;
+        } else {
+            multiplicity += 1;
+            sigma[i] = if signs.next().unwrap() { 1.0 } else { -1.0 };
+        }
+    }
+
+    let (g2, gamma2, multiplicity) = if let Some(next) = next {
+        (
+            next.homogenous,
+            next.inhomogenous,
+            multiplicity + next.multiplicity,
+        )
+    } else {
+        (DMatrix::identity(m, m), DVector::zeros(m), multiplicity)
+    };
+
+    // Calculate YΣA = YΣ·(I - L·Σ)⁻¹ by back substitution over columns of YΣ
+    let g2ysamat = {
+        let mut g2_ymat_sigma = y_tape.mul_left(&g2);
+        for i in 0..g2.nrows() {
+            for j in 0..s {
+                g2_ymat_sigma[(i, j)] *= sigma[j];
+            }
+        }
+        back_solve_lower(&l_mat, &sigma, &g2_ymat_sigma)
+    };
+
+    let homogenous = j_tape.mul_left(&g2) + z_tape.mul_left(&g2ysamat);
+    let inhomogenous = gamma2 + &g2 * b + g2ysamat * a;
+    GeneralizedJacobian {
+        homogenous,
+        inhomogenous,
+        multiplicity,
+    }
+}
+
+/// Older bounded-iteration implementation of [`generalized_jacobian_tape`], kept for
+/// cross-validation against the substitution-based solve
+///
+/// Computes the same `Δz` and `YΣA` fixed points by iterating each recurrence up to `s` times and
+/// relying on exact `PartialEq` on floats for early termination, instead of the exact O(s²)
+/// triangular substitution `generalized_jacobian_tape` now uses.
+#[allow(clippy::many_single_char_names)]
+pub fn generalized_jacobian_tape_iterative(
+    tape: Box<dyn Tape<f64>>,
+    dx: &DVector<f64>,
+    sign_bits: &[u8],
+    next: Option<GeneralizedJacobian>,
+) -> GeneralizedJacobian {
+    let n = tape.num_indeps();
+    let m = tape.num_deps();
+    let s = tape.num_abs();
+    assert_eq!(dx.nrows(), n);
+
+    let abs_tape = AbsNormalTape::new(tape);
+
+    // Create subtapes
+    let z_tape = AbsNormalZ::new(&abs_tape);
+    let l_tape = AbsNormalL::new(&abs_tape);
+    let j_tape = AbsNormalJ::new(&abs_tape);
+    let y_tape = AbsNormalY::new(&abs_tape);
+
+    // Calculate a and b
+    let z = abs_tape.z();
+    let z_abs = z.abs();
+    let a = &z - l_tape.mul_right(&z_abs);
+    let b = -y_tape.mul_right(&z_abs);
+
     // Calculate Δz
     let dzt = &a + z_tape.mul_right(&dx);
     let mut dz = dzt.clone();
@@ -145,6 +253,141 @@ pub fn generalized_jacobian_tape(
     }
 }
 
+/// Derive the Generalized Jacobian of a function using `m` direct reverse sweeps
+///
+/// Equivalent to [`generalized_jacobian`], but goes through [`generalized_jacobian_reverse_tape`]
+/// instead of [`generalized_jacobian_tape`].
+pub fn generalized_jacobian_reverse(
+    func: &dyn Function,
+    x: &DVector<f64>,
+    dx: &DVector<f64>,
+    sign_bits: &[u8],
+    next: Option<GeneralizedJacobian>,
+) -> GeneralizedJacobian {
+    assert_eq!(x.nrows(), func.n());
+    let tape = func.tape(x);
+    generalized_jacobian_reverse_tape(tape, dx, sign_bits, next)
+}
+
+/// Derive the Generalized Jacobian of a tape using `m` direct reverse sweeps over the original
+/// tape instead of assembling it from the `Z`/`L`/`J`/`Y` sub-tapes
+///
+/// `Operation::first_order_reverse` panics on `OpCode::Abs`, since `∂|z|/∂z` has no single value
+/// at a kink. This resolves the same `σ` signature [`generalized_jacobian_tape`] does, from the
+/// abs-normal fixed point of `Δz = Δz_t + L·|Δz|`, and then reverse-sweeps the original tape
+/// directly once per row of `next` (or once per dependent, if `next` is `None`), substituting
+/// `σ_i` for `Abs`'s local derivative at each switching point instead of going through the matrix
+/// algebra `J + Y·Σ·(I - L·Σ)⁻¹·Z`. Produces the same `homogenous` (the generalized Jacobian
+/// itself) as [`generalized_jacobian_tape`]; the affine `inhomogenous` offset still needs the
+/// fixed point of `z`, so it's computed the same way.
+#[allow(clippy::many_single_char_names)]
+pub fn generalized_jacobian_reverse_tape(
+    tape: Box<dyn Tape<f64>>,
+    dx: &DVector<f64>,
+    sign_bits: &[u8],
+    next: Option<GeneralizedJacobian>,
+) -> GeneralizedJacobian {
+    let n = tape.num_indeps();
+    let m = tape.num_deps();
+    let s = tape.num_abs();
+    assert_eq!(dx.nrows(), n);
+
+    // Snapshot the raw tape -- with real `Abs` ops, not masked to `Nop` -- before it is decomposed
+    // below, so the direct reverse sweep can apply `σ` in place instead of going through the
+    // `Z`/`L`/`J`/`Y` sub-tapes.
+    let raw_indeps: Vec<usize> = tape.indeps().to_vec();
+    let raw_deps: Vec<usize> = tape.deps().to_vec();
+    let raw_ops: Vec<Operation> = tape.ops_iter().collect();
+    let raw_values: Vec<f64> = tape.values().to_vec();
+    let mut abs_index = vec![usize::MAX; raw_values.len()];
+    for (j, op) in raw_ops.iter().filter(|op| op.opcode == OpCode::Abs).enumerate() {
+        abs_index[op.vid] = j;
+    }
+
+    let abs_tape = AbsNormalTape::new(tape);
+    let z_tape = AbsNormalZ::new(&abs_tape);
+    let l_tape = AbsNormalL::new(&abs_tape);
+    let y_tape = AbsNormalY::new(&abs_tape);
+
+    let z = abs_tape.z();
+    let z_abs = z.abs();
+    let a = &z - l_tape.mul_right(&z_abs);
+    let b = -y_tape.mul_right(&z_abs);
+    let l_mat = l_tape.mul_left(&DMatrix::<f64>::identity(s, s));
+
+    // Resolve σ from the same Δz fixed point as `generalized_jacobian_tape`
+    let dzt = &a + z_tape.mul_right(dx);
+    let mut dz = DVector::zeros(s);
+    let mut sigma = DVector::zeros(s);
+    let mut multiplicity = 0_usize;
+    let mut signs = bit_iter(sign_bits);
+    for i in 0..s {
+        let mut row_sum = 0.0;
+        for j in 0..i {
+            row_sum += l_mat[(i, j)] * dz[j].abs();
+        }
+        dz[i] = dzt[i] + row_sum;
+        if dz[i] < 0.0 {
+            sigma[i] = -1.0;
+        } else if dz[i] > 0.0 {
+            sigma[i] = 1.0;
+        } else {
+            multiplicity += 1;
+            sigma[i] = if signs.next().unwrap() { 1.0 } else { -1.0 };
+        }
+    }
+
+    let (g2, gamma2, multiplicity) = if let Some(next) = next {
+        (
+            next.homogenous,
+            next.inhomogenous,
+            multiplicity + next.multiplicity,
+        )
+    } else {
+        (DMatrix::identity(m, m), DVector::zeros(m), multiplicity)
+    };
+
+    // Inhomogenous offset: YΣA = YΣ·(I - L·Σ)⁻¹ by back substitution, as in
+    // `generalized_jacobian_tape`
+    let g2ysamat = {
+        let mut g2_ymat_sigma = y_tape.mul_left(&g2);
+        for i in 0..g2.nrows() {
+            for j in 0..s {
+                g2_ymat_sigma[(i, j)] *= sigma[j];
+            }
+        }
+        back_solve_lower(&l_mat, &sigma, &g2_ymat_sigma)
+    };
+    let inhomogenous = gamma2 + &g2 * b + g2ysamat * a;
+
+    // Homogenous Jacobian: one direct reverse sweep of the original tape per row of `g2`,
+    // treating every `Abs` as multiplication by its now-fixed `σ`
+    let mut homogenous = DMatrix::zeros(g2.nrows(), n);
+    for i in 0..g2.nrows() {
+        let mut vbar = vec![0.0_f64; raw_values.len()];
+        for (k, &vid) in raw_deps.iter().enumerate() {
+            vbar[vid] += g2[(i, k)];
+        }
+        for op in raw_ops.iter().rev() {
+            if op.opcode == OpCode::Abs {
+                let j = abs_index[op.vid];
+                vbar[op.arg1.unwrap()] += vbar[op.vid] * sigma[j];
+            } else {
+                op.first_order_reverse(&raw_values, &mut vbar);
+            }
+        }
+        for (j, &vid) in raw_indeps.iter().enumerate() {
+            homogenous[(i, j)] = vbar[vid];
+        }
+    }
+
+    GeneralizedJacobian {
+        homogenous,
+        inhomogenous,
+        multiplicity,
+    }
+}
+
 /// Derive the Generalized Jacobian of a chain of functions
 pub fn generalized_jacobian_chain(
     chain: &FunctionChain,
@@ -232,6 +475,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn halfpipe_function_via_function_ext() {
+        let func = adv_fn_obj!(halfpipe);
+        let x = DVector::from_vec(vec![1.0, 1.5]);
+        let dx = DVector::from_vec(vec![0.5, 0.0]);
+        let jac_ref = generalized_jacobian(&func, &x, &dx, &[0], None);
+        assert_eq!(func.generalized_jacobian(&x, &dx), jac_ref);
+    }
+
     #[test]
     fn halfpipe_function_with_next() {
         let func = adv_fn_obj!(halfpipe);
@@ -256,6 +508,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn halfpipe_substitution_matches_iterative() {
+        let func = adv_fn_obj!(halfpipe);
+
+        for x1 in (0..10).map(|i| (i as f64) * 0.5) {
+            for x2 in (0..10).map(|i| (i as f64) * 0.5) {
+                for dx1 in (0..2).map(|i| (i as f64) * 0.5) {
+                    for dx2 in (0..2).map(|i| (i as f64) * 0.5) {
+                        let x = DVector::from_vec(vec![x1, x2]);
+                        let dx = DVector::from_vec(vec![dx1, dx2]);
+                        let jac_substitution = generalized_jacobian_tape(
+                            func.tape(&x),
+                            &dx,
+                            &[0],
+                            None,
+                        );
+                        let jac_iterative = generalized_jacobian_tape_iterative(
+                            func.tape(&x),
+                            &dx,
+                            &[0],
+                            None,
+                        );
+                        assert_eq!(jac_substitution, jac_iterative);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn halfpipe_reverse_matches_tape() {
+        let func = adv_fn_obj!(halfpipe);
+
+        for x1 in (0..10).map(|i| (i as f64) * 0.5) {
+            for x2 in (0..10).map(|i| (i as f64) * 0.5) {
+                for dx1 in (0..2).map(|i| (i as f64) * 0.5) {
+                    for dx2 in (0..2).map(|i| (i as f64) * 0.5) {
+                        let x = DVector::from_vec(vec![x1, x2]);
+                        let dx = DVector::from_vec(vec![dx1, dx2]);
+                        let jac_tape = generalized_jacobian_tape(func.tape(&x), &dx, &[0], None);
+                        let jac_reverse =
+                            generalized_jacobian_reverse_tape(func.tape(&x), &dx, &[0], None);
+                        assert_eq!(jac_tape, jac_reverse);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn halfpipe_reverse_via_function() {
+        let func = adv_fn_obj!(halfpipe);
+        let x = DVector::from_vec(vec![1.0, 1.5]);
+        let dx = DVector::from_vec(vec![0.5, 0.0]);
+        let jac_tape = generalized_jacobian(&func, &x, &dx, &[0], None);
+        let jac_reverse = generalized_jacobian_reverse(&func, &x, &dx, &[0], None);
+        assert_eq!(jac_tape, jac_reverse);
+    }
+
     #[test]
     fn halfpipe_function_chain() {
         let mut chain = FunctionChain::new(adv_fn_obj!(halfpipe_1));
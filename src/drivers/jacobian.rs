@@ -1,31 +1,87 @@
 use super::*;
 use rayon::prelude::*;
+use std::sync::Arc;
 
-/// Create a jacobian using forward-mode automatic differentiation
+/// Create a jacobian using forward-mode automatic differentiation, under rayon's global pool
+///
+/// Equivalent to [`jacobian_forward_with`] with the default [`Worker`].
 #[allow(clippy::many_single_char_names)]
 pub fn jacobian_forward(func: &dyn Function<ADouble>, x: &DVector<f64>) -> DMatrix<f64> {
+    jacobian_forward_with(func, x, &Worker::default())
+}
+
+/// Create a jacobian using forward-mode automatic differentiation, under an explicit [`Worker`]
+#[allow(clippy::many_single_char_names)]
+pub fn jacobian_forward_with(
+    func: &dyn Function<ADouble>,
+    x: &DVector<f64>,
+    worker: &Worker,
+) -> DMatrix<f64> {
     let n = func.n();
     let m = func.m();
     assert_eq!(x.nrows(), func.n());
 
-    let columns = (0..n)
+    let columns = worker.map(&(0..n).collect::<Vec<usize>>(), |j| {
+        let mut dx = DVector::from_element(n, 0.0);
+        dx[*j] = 1.0;
+        let input = DVector::from_vec(
+            x.as_slice()
+                .iter()
+                .cloned()
+                .zip(dx.into_iter())
+                .map(|(x, dx)| ADouble::new(x, *dx))
+                .collect(),
+        );
+        let output = func.eval(input);
+        DVector::from_vec(output.as_slice().iter().map(|y| y.dvalue()).collect())
+    });
+
+    let mut jacobian = DMatrix::from_element(m, n, 0.0);
+    for (j, dy) in columns.into_iter().enumerate() {
+        for i in 0..m {
+            jacobian[(i, j)] = dy[i];
+        }
+    }
+
+    jacobian
+}
+
+/// Create a jacobian using blocked forward-mode automatic differentiation
+///
+/// Instead of seeding one input direction per evaluation like [`jacobian_forward`], the input is
+/// partitioned into blocks of `K` directions carried by [`ADoubleK`], so the function only has
+/// to be evaluated `ceil(n / K)` times instead of `n` times.
+#[allow(clippy::many_single_char_names)]
+pub fn jacobian_forward_blocked<const K: usize>(
+    func: &dyn Function<ADoubleK<K>>,
+    x: &DVector<f64>,
+) -> DMatrix<f64> {
+    let n = func.n();
+    let m = func.m();
+    assert_eq!(x.nrows(), n);
+
+    let nblocks = (n + K - 1) / K;
+
+    let blocks = (0..nblocks)
         .collect::<Vec<usize>>()
         .par_iter()
-        .map(|j| {
-            let mut dx = DVector::from_element(n, 0.0);
-            dx[*j] = 1.0;
+        .map(|block| {
+            let offset = block * K;
+            let width = K.min(n - offset);
             let input = DVector::from_vec(
-                x.as_slice()
-                    .iter()
-                    .cloned()
-                    .zip(dx.into_iter())
-                    .map(|(x, dx)| ADouble::new(x, *dx))
+                (0..n)
+                    .map(|i| {
+                        let mut dv = [0.0; K];
+                        if i >= offset && i < offset + width {
+                            dv[i - offset] = 1.0;
+                        }
+                        ADoubleK::new(x[i], dv)
+                    })
                     .collect(),
             );
             let output = func.eval(input);
-            let dy = DVector::from_vec(output.as_slice().iter().map(|y| y.dvalue()).collect());
 
-            (*j, dy)
+            (offset, width, output)
         })
         .map(|x| vec![x])
         .reduce(Vec::new, |mut a, mut b| {
@@ -34,39 +90,39 @@ pub fn jacobian_forward(func: &dyn Function<ADouble>, x: &DVector<f64>) -> DMatr
         });
 
     let mut jacobian = DMatrix::from_element(m, n, 0.0);
-    for (j, dy) in columns {
-        for i in 0..m {
-            jacobian[(i, j)] = dy[i];
+    for (offset, width, output) in blocks {
+        for (i, y) in output.as_slice().iter().enumerate() {
+            for k in 0..width {
+                jacobian[(i, offset + k)] = y.dvalue()[k];
+            }
         }
     }
 
     jacobian
 }
 
-/// Create a jacobian using reverse-mode automatic differentiation
+/// Create a jacobian using reverse-mode automatic differentiation, under rayon's global pool
+///
+/// Equivalent to [`jacobian_reverse_with`] with the default [`Worker`].
 #[allow(clippy::many_single_char_names)]
 pub fn jacobian_reverse(tape: &dyn Tape<f64>) -> DMatrix<f64> {
+    jacobian_reverse_with(tape, &Worker::default())
+}
+
+/// Create a jacobian using reverse-mode automatic differentiation, under an explicit [`Worker`]
+#[allow(clippy::many_single_char_names)]
+pub fn jacobian_reverse_with(tape: &dyn Tape<f64>, worker: &Worker) -> DMatrix<f64> {
     let n = tape.num_indeps();
     let m = tape.num_deps();
 
-    let rows = (0..m)
-        .collect::<Vec<usize>>()
-        .par_iter()
-        .map(|i| {
-            let mut ybar = DVector::from_element(m, 0.0);
-            ybar[*i] = 1.0;
-
-            let xbar = tape.first_order_reverse(&ybar);
-            (*i, xbar)
-        })
-        .map(|x| vec![x])
-        .reduce(Vec::new, |mut a, mut b| {
-            a.append(&mut b);
-            a
-        });
+    let rows = worker.map(&(0..m).collect::<Vec<usize>>(), |i| {
+        let mut ybar = DVector::from_element(m, 0.0);
+        ybar[*i] = 1.0;
+        tape.first_order_reverse(&ybar)
+    });
 
     let mut jacobian = DMatrix::from_element(m, n, 0.0);
-    for (i, xbar) in rows {
+    for (i, xbar) in rows.into_iter().enumerate() {
         for j in 0..n {
             jacobian[(i, j)] = xbar[j];
         }
@@ -75,6 +131,42 @@ pub fn jacobian_reverse(tape: &dyn Tape<f64>) -> DMatrix<f64> {
     jacobian
 }
 
+/// Create a jacobian using a dedicated thread pool capped to `nthreads`, sharing a single tape
+/// across all sweeps
+///
+/// Depending on whether the function has more independents or dependents, either a forward-mode
+/// sweep per column or a reverse-mode sweep per row is used, mirroring [`jacobian_forward`] and
+/// [`jacobian_reverse`] respectively. A thin wrapper around [`Worker::with_threads`]; use
+/// [`jacobian_forward_with`]/[`jacobian_reverse_with`] directly for more control over the
+/// execution policy (e.g. a shared pool or sequential fallback).
+#[allow(clippy::many_single_char_names)]
+pub fn jacobian_parallel(func: &dyn Function, x: &DVector<f64>, nthreads: usize) -> DMatrix<f64> {
+    assert_eq!(x.nrows(), func.n());
+
+    let tape: Arc<dyn Tape<f64>> = Arc::from(func.tape(x));
+    let n = tape.num_indeps();
+    let m = tape.num_deps();
+    let worker = Worker::with_threads(nthreads);
+
+    if n <= m {
+        let columns = worker.map(&(0..n).collect::<Vec<usize>>(), |j| {
+            let mut xdot = DVector::from_element(n, 0.0);
+            xdot[*j] = 1.0;
+            tape.first_order_forward(&xdot)
+        });
+
+        let mut jacobian = DMatrix::from_element(m, n, 0.0);
+        for (j, ydot) in columns.into_iter().enumerate() {
+            for i in 0..m {
+                jacobian[(i, j)] = ydot[i];
+            }
+        }
+        jacobian
+    } else {
+        jacobian_reverse_with(&*tape, &worker)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +226,56 @@ mod tests {
 
         assert_eq!(jacobian, reference);
     }
+
+    #[test]
+    fn jacobian_forward_blocked_polar() {
+        let mut polar = DVector::from_element(2, 0.0);
+        polar[0] = 2.0;
+        polar[1] = std::f64::consts::PI;
+
+        let reference = reference_jacobian(&polar);
+        let jacobian = jacobian_forward_blocked::<2>(&adv_fn_obj!(test_function), &polar);
+
+        assert_eq!(jacobian, reference);
+    }
+
+    #[test]
+    fn jacobian_parallel_polar() {
+        let mut polar = DVector::from_element(2, 0.0);
+        polar[0] = 2.0;
+        polar[1] = std::f64::consts::PI;
+
+        let reference = reference_jacobian(&polar);
+        let jacobian = jacobian_parallel(&adv_fn_obj!(test_function), &polar, 2);
+
+        assert_eq!(jacobian, reference);
+    }
+
+    #[test]
+    fn jacobian_forward_sequential_worker_polar() {
+        let mut polar = DVector::from_element(2, 0.0);
+        polar[0] = 2.0;
+        polar[1] = std::f64::consts::PI;
+
+        let reference = reference_jacobian(&polar);
+        let jacobian =
+            jacobian_forward_with(&adv_fn_obj!(test_function), &polar, &Worker::Sequential);
+
+        assert_eq!(jacobian, reference);
+    }
+
+    #[test]
+    fn jacobian_reverse_sequential_worker_polar() {
+        let mut tape = test_function_tape();
+
+        let mut polar = DVector::from_element(2, 0.0);
+        polar[0] = 2.0;
+        polar[1] = std::f64::consts::PI;
+        tape.zero_order(&polar);
+
+        let reference = reference_jacobian(&polar);
+        let jacobian = jacobian_reverse_with(&tape, &Worker::Sequential);
+
+        assert_eq!(jacobian, reference);
+    }
 }
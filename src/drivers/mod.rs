@@ -4,15 +4,30 @@ use super::*;
 mod generalized_jacobian;
 pub use generalized_jacobian::*;
 
+mod worker;
+pub use worker::*;
+
 mod jacobian;
 pub use jacobian::*;
 
+mod sparse;
+pub use sparse::*;
+
 mod abs_normal;
 pub use abs_normal::*;
 
+mod pl_solve;
+pub use pl_solve::*;
+
+mod abs_normal_newton;
+pub use abs_normal_newton::*;
+
 mod checkpointing;
 pub use checkpointing::*;
 
+mod optimize;
+pub use optimize::*;
+
 #[cfg(test)]
 mod testfunc;
 #[cfg(test)]
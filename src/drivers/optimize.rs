@@ -0,0 +1,184 @@
+use super::*;
+use std::collections::VecDeque;
+
+/// Options controlling [`minimize`]
+#[derive(Debug, Clone)]
+pub struct OptimizeOptions {
+    /// Maximum number of outer iterations
+    pub max_iter: usize,
+    /// Number of curvature pairs kept for the L-BFGS two-loop recursion
+    pub memory: usize,
+    /// Stop once the gradient norm drops below this tolerance
+    pub grad_tol: f64,
+    /// Armijo sufficient-decrease constant
+    pub c1: f64,
+    /// Backtracking line search shrink factor
+    pub backtrack: f64,
+    /// Maximum number of backtracking steps per outer iteration
+    pub max_line_search: usize,
+}
+
+impl Default for OptimizeOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 100,
+            memory: 10,
+            grad_tol: 1e-8,
+            c1: 1e-4,
+            backtrack: 0.5,
+            max_line_search: 50,
+        }
+    }
+}
+
+/// Result of [`minimize`]
+#[derive(Debug, Clone)]
+pub struct OptimizeResult {
+    /// Parameters at the last accepted iterate
+    pub x: DVector<f64>,
+    /// Objective value at `x`
+    pub value: f64,
+    /// Norm of the gradient at `x`
+    pub gradient_norm: f64,
+    /// Number of outer iterations taken
+    pub iterations: usize,
+    /// Whether `gradient_norm` dropped below [`OptimizeOptions::grad_tol`]
+    pub converged: bool,
+}
+
+/// Re-evaluate `tape` at `x`, returning the objective value and its gradient
+fn eval_grad(tape: &mut dyn Tape<f64>, x: &DVector<f64>) -> (f64, DVector<f64>) {
+    tape.zero_order(x);
+    let value = tape.y()[0];
+    let grad = jacobian_reverse(tape).row(0).transpose();
+    (value, grad)
+}
+
+/// L-BFGS two-loop recursion, approximating `H_k * grad` from the stored curvature pairs
+#[allow(clippy::many_single_char_names)]
+fn two_loop_recursion(
+    grad: &DVector<f64>,
+    history: &VecDeque<(DVector<f64>, DVector<f64>)>,
+) -> DVector<f64> {
+    let mut q = grad.clone();
+    let mut alphas = Vec::with_capacity(history.len());
+
+    for (s, y) in history.iter().rev() {
+        let rho = 1.0 / y.dot(s);
+        let alpha = rho * s.dot(&q);
+        q -= alpha * y;
+        alphas.push(alpha);
+    }
+    alphas.reverse();
+
+    if let Some((s, y)) = history.back() {
+        let gamma = s.dot(y) / y.dot(y);
+        q *= gamma;
+    }
+
+    for ((s, y), alpha) in history.iter().zip(alphas.iter()) {
+        let rho = 1.0 / y.dot(s);
+        let beta = rho * y.dot(&q);
+        q += (alpha - beta) * s;
+    }
+
+    q
+}
+
+/// Minimize the scalar objective recorded on `tape` using limited-memory BFGS
+///
+/// `tape` must have exactly one dependent, the objective value. Starting from `x0`, each
+/// iteration builds a search direction from [`two_loop_recursion`], takes a backtracking Armijo
+/// step along it, and folds the resulting `(s, y)` curvature pair into the history -- unless
+/// `y.dot(&s) <= 0.0`, in which case the pair is skipped to keep the Hessian approximation
+/// positive definite. Stops once the gradient norm drops below `opts.grad_tol`, `opts.max_iter`
+/// is reached, or the line search fails to find a decrease.
+pub fn minimize(tape: &mut dyn Tape<f64>, x0: &DVector<f64>, opts: &OptimizeOptions) -> OptimizeResult {
+    assert_eq!(
+        tape.num_deps(),
+        1,
+        "minimize requires a tape with a single scalar dependent"
+    );
+
+    let mut x = x0.clone();
+    let (mut value, mut grad) = eval_grad(tape, &x);
+    let mut history: VecDeque<(DVector<f64>, DVector<f64>)> = VecDeque::with_capacity(opts.memory);
+
+    let mut iterations = 0;
+    while grad.norm() > opts.grad_tol && iterations < opts.max_iter {
+        let direction = -two_loop_recursion(&grad, &history);
+        let directional_derivative = grad.dot(&direction);
+
+        let mut step = 1.0;
+        let mut accepted = None;
+        for _ in 0..opts.max_line_search {
+            let x_new = &x + step * &direction;
+            let (value_new, grad_new) = eval_grad(tape, &x_new);
+            if value_new <= value + opts.c1 * step * directional_derivative {
+                accepted = Some((x_new, value_new, grad_new));
+                break;
+            }
+            step *= opts.backtrack;
+        }
+
+        let (x_new, value_new, grad_new) = match accepted {
+            Some(accepted) => accepted,
+            None => break,
+        };
+
+        let s = &x_new - &x;
+        let y = &grad_new - &grad;
+        if y.dot(&s) > 0.0 {
+            if history.len() == opts.memory {
+                history.pop_front();
+            }
+            history.push_back((s, y));
+        }
+
+        x = x_new;
+        value = value_new;
+        grad = grad_new;
+        iterations += 1;
+    }
+
+    let gradient_norm = grad.norm();
+    OptimizeResult {
+        x,
+        value,
+        gradient_norm,
+        iterations,
+        converged: gradient_norm <= opts.grad_tol,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    adv_fn! {
+        fn quadratic_bowl(x: [[2]]) -> [[1]] {
+            let dx = x[0] - 3.0;
+            let dy = x[1] + 2.0;
+            adv_dvec![dx * dx + dy * dy]
+        }
+    }
+
+    #[test]
+    fn minimize_quadratic_bowl() {
+        let mut tape = {
+            let mut ctx = AContext::new();
+            let x = DVector::from_vec(ctx.new_indep_vec(2, 0.0));
+            let y = quadratic_bowl(x);
+            ctx.set_dep_slice(y.as_slice());
+            ctx.tape()
+        };
+
+        let x0 = DVector::from_element(2, 0.0);
+        let result = minimize(&mut tape, &x0, &OptimizeOptions::default());
+
+        assert!(result.converged);
+        assert!((result.x[0] - 3.0).abs() < 1e-4);
+        assert!((result.x[1] + 2.0).abs() < 1e-4);
+        assert!(result.value < 1e-6);
+    }
+}
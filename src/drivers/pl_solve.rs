@@ -0,0 +1,123 @@
+use super::*;
+
+/// Result of a [`pl_solve`] run
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlSolveResult {
+    /// Root (or best estimate) of the piecewise-linearization, relative to the starting point
+    pub dx: DVector<f64>,
+    /// Signature vector at the solution, with `sigma[i] = sign(z[i])`
+    pub sigma: DVector<f64>,
+    /// Whether the signature iteration stabilized before `max_iter` was reached
+    pub converged: bool,
+}
+
+/// Find a root of the piecewise-linearization of a square `Function` by signature iteration
+///
+/// Builds the [`AbsNormalForm`] at `x` and repeatedly fixes a signature `sigma` (the sign
+/// pattern of the switching vector `z`), solves the resulting dense linear system for `dx` via
+/// a Householder QR factorization, and updates `sigma` from the new `z` until the signature
+/// stops changing or `max_iter` is exceeded.
+#[allow(clippy::many_single_char_names)]
+pub fn pl_solve(func: &dyn Function, x: &DVector<f64>, max_iter: usize) -> PlSolveResult {
+    assert_eq!(func.n(), func.m());
+    let n = func.n();
+    let s = func.tape(x).num_abs();
+
+    let anf = abs_normal(func, x);
+
+    let (z0, _) = anf.eval(&DVector::zeros(n));
+    let mut sigma = sign_vector(&z0, None);
+
+    let mut dx = DVector::zeros(n);
+    // Always run the QR solve at least once, even when `s == 0` (no switching variables): the
+    // signature loop still needs to solve the one, purely-linear system in that case, and it
+    // converges on the very next signature check since an empty `new_sigma == sigma` trivially.
+    let mut converged = false;
+
+    for _ in 0..max_iter {
+        if converged {
+            break;
+        }
+
+        let az = forward_solve_lower(&anf.lmat, &sigma, &anf.zmat);
+        let a_col = forward_solve_lower(&anf.lmat, &sigma, &DMatrix::from_column_slice(s, 1, anf.a.as_slice()));
+
+        let mut sig_az = az;
+        let mut sig_a = a_col;
+        for i in 0..s {
+            let mut row = sig_az.row(i).clone_owned();
+            row *= sigma[i];
+            sig_az.row_mut(i).copy_from(&row);
+            sig_a[(i, 0)] *= sigma[i];
+        }
+
+        let m = &anf.jmat + &anf.ymat * &sig_az;
+        let rhs = -(&anf.b + &anf.ymat * &sig_a);
+
+        dx = m
+            .qr()
+            .solve(&rhs)
+            .map(|sol| sol.column(0).into_owned())
+            .unwrap_or_else(|| DVector::zeros(n));
+
+        let (z, _) = anf.eval(&dx);
+        let new_sigma = sign_vector(&z, Some(&sigma));
+        converged = new_sigma == sigma;
+        sigma = new_sigma;
+    }
+
+    PlSolveResult {
+        dx,
+        sigma,
+        converged,
+    }
+}
+
+fn sign_vector(z: &DVector<f64>, previous: Option<&DVector<f64>>) -> DVector<f64> {
+    let mut sigma = DVector::zeros(z.nrows());
+    for i in 0..z.nrows() {
+        sigma[i] = if z[i] > 0.0 {
+            1.0
+        } else if z[i] < 0.0 {
+            -1.0
+        } else {
+            previous.map(|p| p[i]).unwrap_or(-1.0)
+        };
+    }
+    sigma
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    adv_fn! {
+        fn abs_root(x: [[1]]) -> [[1]] {
+            adv_dvec![x[0].abs() - 1.0]
+        }
+    }
+
+    #[test]
+    fn pl_solve_abs_root() {
+        let result = pl_solve(&adv_fn_obj!(abs_root), &DVector::from_vec(vec![5.0]), 10);
+        assert!(result.converged);
+        let x = DVector::from_vec(vec![5.0]) + &result.dx;
+        assert!((x[0].abs() - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn pl_solve_linear_root() {
+        let func = adv_fn_obj!(two_to_one_affine);
+        let x = DVector::from_vec(vec![0.0]);
+        let result = pl_solve(&func, &x, 10);
+        assert!(result.converged);
+        let solved = x + &result.dx;
+        assert!((solved[0] - 3.0).abs() < 1e-8);
+    }
+
+    adv_fn! {
+        fn two_to_one_affine(x: [[1]]) -> [[1]] {
+            adv_dvec![2.0 * x[0] - 6.0]
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// Execution policy controlling how the Jacobian drivers parallelize column/row sweeps
+///
+/// Mirrors the execution-policy abstraction used by other multicore-aware crates (e.g. bellman's
+/// `multicore::Worker`): a caller can pin a dedicated [`rayon::ThreadPool`], cap the degree of
+/// parallelism, or fall back to strictly sequential execution, independent of whatever pool the
+/// rest of the process happens to be using. [`Worker::default`] reproduces today's behavior
+/// (rayon's global pool, one task per column/row).
+#[derive(Clone)]
+pub enum Worker {
+    /// Run every sweep on the calling thread; no rayon task is ever spawned
+    Sequential,
+    /// Use rayon's global thread pool
+    Global,
+    /// Use a caller-owned dedicated thread pool
+    Pool(Arc<rayon::ThreadPool>),
+}
+
+impl Default for Worker {
+    fn default() -> Self {
+        Worker::Global
+    }
+}
+
+impl Worker {
+    /// Build a dedicated pool capped to `nthreads` threads
+    pub fn with_threads(nthreads: usize) -> Self {
+        Worker::Pool(Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(nthreads)
+                .build()
+                .unwrap(),
+        ))
+    }
+
+    /// Degree of parallelism this worker will actually use
+    pub fn num_threads(&self) -> usize {
+        match self {
+            Worker::Sequential => 1,
+            Worker::Global => rayon::current_num_threads(),
+            Worker::Pool(pool) => pool.current_num_threads(),
+        }
+    }
+
+    /// Chunk size that strip-mines `len` units of work evenly across [`Worker::num_threads`]
+    ///
+    /// For a tall Jacobian with few outputs, this keeps the forward driver from spawning one
+    /// rayon task per input seed -- each thread instead sweeps a contiguous chunk of seeds.
+    pub fn chunk_size(&self, len: usize) -> usize {
+        let threads = self.num_threads().max(1);
+        (len + threads - 1) / threads
+    }
+
+    /// Map `items` to `R` under this worker's policy, preserving order
+    pub fn map<T, R, F>(&self, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Send + Sync,
+    {
+        match self {
+            Worker::Sequential => items.iter().map(|item| f(item)).collect(),
+            Worker::Global => items.par_iter().map(|item| f(item)).collect(),
+            Worker::Pool(pool) => {
+                let chunk_size = self.chunk_size(items.len());
+                pool.install(|| Self::chunked_map(items, chunk_size, &f))
+            }
+        }
+    }
+
+    fn chunked_map<T, R, F>(items: &[T], chunk_size: usize, f: &F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Send + Sync,
+    {
+        items
+            .par_chunks(chunk_size.max(1))
+            .flat_map(|chunk| chunk.iter().map(f).collect::<Vec<_>>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequential_preserves_order() {
+        let items = (0..10).collect::<Vec<_>>();
+        let squares = Worker::Sequential.map(&items, |i| i * i);
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn global_preserves_order() {
+        let items = (0..37).collect::<Vec<_>>();
+        let squares = Worker::Global.map(&items, |i| i * i);
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn pool_preserves_order_and_caps_threads() {
+        let worker = Worker::with_threads(2);
+        assert_eq!(worker.num_threads(), 2);
+
+        let items = (0..37).collect::<Vec<_>>();
+        let squares = worker.map(&items, |i| i * i);
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn chunk_size_strip_mines_evenly() {
+        let worker = Worker::with_threads(4);
+        assert_eq!(worker.chunk_size(16), 4);
+        assert_eq!(worker.chunk_size(17), 5);
+    }
+}
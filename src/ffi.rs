@@ -1,4 +1,5 @@
 #![allow(non_camel_case_types)]
+use super::drivers::*;
 use super::*;
 use nalgebra::{DMatrix, DVector};
 use num::Zero;
@@ -214,3 +215,79 @@ macro_rules! binary_function {
 
 binary_function!(min);
 binary_function!(max);
+
+// `Tape` bindings
+
+#[no_mangle]
+pub extern "C" fn adv_tape_new(ctx: &AContext) -> *mut Box<dyn Tape<f64>> {
+    Box::leak(Box::new(Box::new(ctx.tape()) as Box<dyn Tape<f64>>))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn adv_tape_free(this: *mut Box<dyn Tape<f64>>) {
+    Box::from_raw(this);
+}
+
+#[no_mangle]
+pub extern "C" fn adv_tape_num_indeps(this: &Box<dyn Tape<f64>>) -> usize {
+    this.num_indeps()
+}
+
+#[no_mangle]
+pub extern "C" fn adv_tape_num_deps(this: &Box<dyn Tape<f64>>) -> usize {
+    this.num_deps()
+}
+
+#[no_mangle]
+pub extern "C" fn adv_tape_num_abs(this: &Box<dyn Tape<f64>>) -> usize {
+    this.num_abs()
+}
+
+// `drivers` bindings
+
+#[no_mangle]
+pub unsafe extern "C" fn adv_jacobian(
+    this: &mut Box<dyn Tape<f64>>,
+    x: adv_const_vector,
+    result: adv_matrix,
+) -> adv_error {
+    let mut result = result;
+    let x = x.to_dvec();
+    if x.nrows() != this.num_indeps() {
+        return adv_error::ADV_ERROR_DIM_MISMATCH;
+    }
+    this.zero_order(&x);
+    result.copy_from_dmat(&jacobian_reverse(&**this)).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn adv_generalized_jacobian(
+    this: *mut Box<dyn Tape<f64>>,
+    x: adv_const_vector,
+    dx: adv_const_vector,
+    homogeneous: adv_matrix,
+    inhomogeneous: adv_vector,
+    multiplicity: &mut usize,
+) -> adv_error {
+    let mut homogeneous = homogeneous;
+    let mut inhomogeneous = inhomogeneous;
+
+    let x = x.to_dvec();
+    let dx = dx.to_dvec();
+    // Validate dimensions through a borrow before taking ownership of `this` below, so a
+    // mismatch leaves the caller's handle intact instead of freeing it out from under them.
+    let num_indeps = (*this).num_indeps();
+    if x.nrows() != num_indeps || dx.nrows() != num_indeps {
+        return adv_error::ADV_ERROR_DIM_MISMATCH;
+    }
+
+    let mut tape = *Box::from_raw(this);
+    tape.zero_order(&x);
+
+    let jac = generalized_jacobian_tape(tape, &dx, &[0], None);
+    *multiplicity = jac.multiplicity;
+    match homogeneous.copy_from_dmat(&jac.homogenous) {
+        Ok(()) => inhomogeneous.copy_from_dvec(&jac.inhomogenous).into(),
+        Err(err) => err,
+    }
+}
@@ -5,19 +5,14 @@ use std::ops::{Div, DivAssign};
 use std::ops::{Mul, MulAssign};
 use std::ops::{Sub, SubAssign};
 
-/// Type behaving like a floating-point number
-pub trait Float: num::Float + fmt::Debug + Send + Sync + 'static {}
-
-impl<T> Float for T where T: num::Float + fmt::Debug + Send + Sync + 'static {}
-assert_impl_all!(f64: Float);
-assert_impl_all!(ADouble: Float);
-
 /// Type supporting all arithmetic operations resulting in a certain type
 pub trait Arithmetic<R, T>:
     Sized
     + Clone
     + Copy
-    + Float
+    + Send
+    + Sync
+    + 'static
     + Add<R, Output = T>
     + Sub<R, Output = T>
     + Mul<R, Output = T>
@@ -29,7 +24,9 @@ impl<L, R, T> Arithmetic<R, T> for L where
     L: Sized
         + Clone
         + Copy
-        + Float
+        + Send
+        + Sync
+        + 'static
         + Add<R, Output = T>
         + Sub<R, Output = T>
         + Mul<R, Output = T>
@@ -53,3 +50,33 @@ impl<L, R> ArithmeticAssign<R> for L where
 assert_impl_all!(f64: ArithmeticAssign<f64>);
 assert_impl_all!(ADouble: ArithmeticAssign<ADouble>);
 assert_impl_all!(ADouble: ArithmeticAssign<f64>);
+
+/// Minimal scalar ring `AContext`, `Tape` and `Operation` recording require
+///
+/// This captures only `Add`/`Sub`/`Mul`/`Div` plus the bounds needed to shuttle values through a
+/// tape (`Debug`, `PartialEq`, `Send + Sync + 'static`) -- unlike [`Float`], it does not require
+/// [`num::Float`], so [`AContext::record`] and [`Tape`] accept scalar types with no meaningful
+/// `sin`/`exp`/`ln` (e.g. modular/finite-field arithmetic). Replaying or differentiating a recorded
+/// tape (`TapeExt::zero_order`, `first_order_forward`, `first_order_reverse`) requires
+/// [`Transcendental`], even for a tape that only holds `Add`/`Sub`/`Mul`/`Div`, and the
+/// `AFloat`-based ergonomic constructors on [`AContext`] stay gated behind [`Float`] itself,
+/// since they return `AFloat<S>` values which require the full transcendental surface to exist.
+pub trait Scalar: fmt::Debug + PartialEq + Arithmetic<Self, Self> + ArithmeticAssign<Self> {}
+
+impl<T> Scalar for T where T: fmt::Debug + PartialEq + Arithmetic<T, T> + ArithmeticAssign<T> {}
+assert_impl_all!(f64: Scalar);
+assert_impl_all!(ADouble: Scalar);
+
+/// A [`Scalar`] that additionally supports the transcendental `OpCode`s
+pub trait Transcendental: Scalar + num::Float {}
+
+impl<T> Transcendental for T where T: Scalar + num::Float {}
+assert_impl_all!(f64: Transcendental);
+assert_impl_all!(ADouble: Transcendental);
+
+/// Type behaving like a floating-point number
+pub trait Float: Transcendental + fmt::Debug + Send + Sync + 'static {}
+
+impl<T> Float for T where T: Transcendental + fmt::Debug + Send + Sync + 'static {}
+assert_impl_all!(f64: Float);
+assert_impl_all!(ADouble: Float);
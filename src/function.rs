@@ -270,4 +270,26 @@ mod tests {
         let y = obj.eval(x).map(|x| x.value());
         assert_eq!(y.nrows(), 10);
     }
+
+    adv_sfn! {
+        fn sax(v: [[3]], a: f64) -> [[3]] {
+            v.map(|x| a * x)
+        }
+    }
+
+    #[test]
+    fn stack_allocated_macro_function() {
+        let x = adv_svec!(1.0, 2.0, 3.0);
+        let y = sax(x, 2.0);
+        assert_eq!(y[0], 2.0);
+        assert_eq!(y[1], 4.0);
+        assert_eq!(y[2], 6.0);
+
+        let func_obj = adv_sfn_obj!(sax, 2.0);
+        assert_eq!(func_obj.n(), 3);
+        assert_eq!(func_obj.m(), 3);
+
+        let y = func_obj.eval_float(DVector::from_vec(vec![1.0, 2.0, 3.0]));
+        assert_eq!(y, DVector::from_vec(vec![2.0, 4.0, 6.0]));
+    }
 }
@@ -8,7 +8,7 @@ extern crate lazy_static;
 #[macro_use]
 extern crate static_assertions;
 
-pub use nalgebra::{DMatrix, DVector};
+pub use nalgebra::{Const, DMatrix, DVector, OVector, SVector};
 use num::Float as _;
 
 pub mod drivers;
@@ -19,9 +19,18 @@ pub use macros::*;
 mod acontext;
 pub use acontext::*;
 
+mod adoublek;
+pub use adoublek::*;
+
 mod afloat;
 pub use afloat::*;
 
+mod afloat2;
+pub use afloat2::*;
+
+mod afloatn;
+pub use afloatn::*;
+
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
@@ -49,4 +58,7 @@ pub mod prelude {
     pub use super::adv_dvec;
     pub use super::adv_fn;
     pub use super::adv_fn_obj;
+    pub use super::adv_sfn;
+    pub use super::adv_sfn_obj;
+    pub use super::adv_svec;
 }
@@ -98,6 +98,73 @@ macro_rules! adv_fn {
     };
 }
 
+/// Stack-allocated, const-generic sibling of [`adv_fn!`]
+///
+/// Lowers to `nalgebra::SVector<Scalar, N>` instead of `DVector<Scalar>`, so dimensions are
+/// checked at compile time instead of via a runtime `assert_eq!`, and calling the function does
+/// not heap-allocate. Composed directly (not through the `Function` trait object boundary),
+/// a chain of `adv_sfn!` functions runs allocation-free.
+///
+/// ## Example
+/// ```
+/// # extern crate advantage as adv;
+/// # use adv::prelude::*;
+/// adv_sfn! {
+///     fn sax1(v: [[3]], a: f64) -> [[3]] {
+///         v.map(|x| a * x)
+///     }
+/// }
+///
+/// # fn main() {
+/// let x = adv_svec!(1.0, 2.0, 3.0);
+/// let y = sax1(x, 2.0);
+/// assert_eq!(y[0], 2.0);
+/// assert_eq!(y[1], 4.0);
+/// assert_eq!(y[2], 6.0);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! adv_sfn {
+    {
+        $(#[$attr:meta])*
+        $vis:vis fn $func_name:ident ( $arg_name:ident : [[$n:expr]] $( , $extra_arg:ident : $extra_type:ty )* $(,)? ) -> [[$m:expr]] {
+            $($tt:tt)*
+        }
+    } => {
+        $(#[$attr])*
+        $vis fn $func_name<Scalar> ( $arg_name: $crate::SVector<Scalar, $n> $(, $extra_arg : $extra_type )* ) -> $crate::SVector<Scalar, $m>
+        where
+            Scalar: $crate::Float + From<f64> + $crate::Arithmetic<f64, Scalar> + $crate::ArithmeticAssign<f64>,
+            f64: $crate::Arithmetic<Scalar, Scalar>,
+        {
+            $($tt)*
+        }
+
+        // FIXME: If $func_name is imported from another module this function is not necessarily
+        // visible
+        $crate::paste::item! {
+            #[doc(hidden)]
+            $vis fn [< __adv_s_ $func_name >]($( $extra_arg : $extra_type ,)*) -> impl $crate::Function {
+                $crate::SimpleFunction::new($n, $m, move |input: $crate::DVector<$crate::ADouble>| {
+                    let input = $crate::SVector::<$crate::ADouble, $n>::from_column_slice(input.as_slice());
+                    let result = $func_name(input $(, $extra_arg.clone() )*);
+                    $crate::DVector::from_column_slice(result.as_slice())
+                })
+            }
+        }
+    };
+}
+
+/// Get the associated metadata for a function defined with `adv_sfn!`
+#[macro_export]
+macro_rules! adv_sfn_obj {
+    ($name:ident $(, $extra_arg:expr )*) => {
+        $crate::paste::expr! {
+            [< __adv_s_ $name >]($($extra_arg ,)*)
+        }
+    }
+}
+
 /// Get the associated metadata for a function defined with `adv_fn!`
 #[macro_export]
 macro_rules! adv_fn_obj {
@@ -135,3 +202,34 @@ macro_rules! adv_dvec {
     ($($x:expr),*) => ($crate::DVector::from_vec(vec![$($x),*]));
     ($($x:expr,)*) => ($crate::adv_dvec!($($x),*));
 }
+
+/// Create an `SVector` containing the arguments
+///
+/// Stack-allocated sibling of [`adv_dvec!`], for use with [`adv_sfn!`]. The length is fixed as
+/// a const generic, so it is checked at compile time rather than at runtime.
+///
+/// ## Example
+/// ```
+/// # extern crate advantage as adv;
+/// # use adv::prelude::*;
+///
+/// # fn main() {
+/// let vec = adv_svec![1.0, 2.0, 3.0];
+/// assert_eq!(vec.nrows(), 3);
+/// assert_eq!(vec[0], 1.0);
+/// assert_eq!(vec[1], 2.0);
+/// assert_eq!(vec[2], 3.0);
+///
+/// let vec = adv_svec![1.0; 3];
+/// assert_eq!(vec.nrows(), 3);
+/// assert_eq!(vec[0], 1.0);
+/// assert_eq!(vec[1], 1.0);
+/// assert_eq!(vec[2], 1.0);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! adv_svec {
+    ($elem:expr; $n:expr) => ($crate::SVector::<_, $n>::from_element($elem));
+    ($($x:expr),*) => ($crate::SVector::from([$($x),*]));
+    ($($x:expr,)*) => ($crate::adv_svec!($($x),*));
+}
@@ -1,7 +1,8 @@
-use num::traits::Float;
+use super::{Scalar, Transcendental};
+use serde::{Deserialize, Serialize};
 
 /// Enum of possible elementary operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OpCode {
     Nop,
     Const,
@@ -19,14 +20,43 @@ pub enum OpCode {
     Acos,
     Atan,
     Powf,
+    Sinh,
+    Cosh,
+    Tanh,
+    Asinh,
+    Acosh,
+    Atanh,
+    ExpM1,
+    Ln1p,
+    Atan2,
+    Floor,
+    Ceil,
+    Round,
+    Trunc,
+    Fract,
+    Signum,
+    Min,
+    Max,
+    Relu,
 }
 
-pub(crate) fn zero_order_value<S: Float>(opcode: OpCode, arg1: S, arg2: Option<S>) -> S {
+/// Evaluate the ring-only `OpCode`s (`Add`/`Sub`/`Mul`/`Div`), available for any [`Scalar`]
+///
+/// Split out from [`zero_order_value`] so a `Scalar` with no transcendental functions (e.g.
+/// modular/finite-field arithmetic) could, in principle, replay plain arithmetic circuits without
+/// pulling in [`Transcendental`].
+pub(crate) fn zero_order_value_scalar<S: Scalar>(opcode: OpCode, arg1: S, arg2: Option<S>) -> S {
     match opcode {
         OpCode::Add => arg1 + arg2.unwrap(),
         OpCode::Sub => arg1 - arg2.unwrap(),
         OpCode::Mul => arg1 * arg2.unwrap(),
         OpCode::Div => arg1 / arg2.unwrap(),
+        _ => panic!("Invalid opcode in zero_order_value_scalar"),
+    }
+}
+
+pub(crate) fn zero_order_value<S: Transcendental>(opcode: OpCode, arg1: S, arg2: Option<S>) -> S {
+    match opcode {
         OpCode::Sin => arg1.sin(),
         OpCode::Cos => arg1.cos(),
         OpCode::Tan => arg1.tan(),
@@ -37,11 +67,38 @@ pub(crate) fn zero_order_value<S: Float>(opcode: OpCode, arg1: S, arg2: Option<S
         OpCode::Acos => arg1.acos(),
         OpCode::Atan => arg1.atan(),
         OpCode::Powf => arg1.powf(arg2.unwrap()),
-        _ => panic!("Invalid opcode in zero_order_value"),
+        OpCode::Sinh => arg1.sinh(),
+        OpCode::Cosh => arg1.cosh(),
+        OpCode::Tanh => arg1.tanh(),
+        OpCode::Asinh => arg1.asinh(),
+        OpCode::Acosh => arg1.acosh(),
+        OpCode::Atanh => arg1.atanh(),
+        OpCode::ExpM1 => arg1.exp_m1(),
+        OpCode::Ln1p => arg1.ln_1p(),
+        OpCode::Atan2 => arg1.atan2(arg2.unwrap()),
+        OpCode::Floor => arg1.floor(),
+        OpCode::Ceil => arg1.ceil(),
+        OpCode::Round => arg1.round(),
+        OpCode::Trunc => arg1.trunc(),
+        OpCode::Fract => arg1.fract(),
+        OpCode::Signum => arg1.signum(),
+        // Lowered to the same `(a+b±|a-b|)/2` identity as the `Abs`-based composition a caller
+        // would otherwise have to hand-write, so these share its numerics exactly.
+        OpCode::Min => {
+            let two = S::one() + S::one();
+            (arg1 + arg2.unwrap() - (arg1 - arg2.unwrap()).abs()) / two
+        }
+        OpCode::Max => {
+            let two = S::one() + S::one();
+            (arg1 + arg2.unwrap() + (arg1 - arg2.unwrap()).abs()) / two
+        }
+        OpCode::Relu => (arg1 + arg1.abs()) / (S::one() + S::one()),
+        _ => zero_order_value_scalar(opcode, arg1, arg2),
     }
 }
 
-pub(crate) fn first_order_value<S: Float>(
+/// Evaluate the ring-only `OpCode`s' directional derivative, available for any [`Scalar`]
+pub(crate) fn first_order_value_scalar<S: Scalar>(
     opcode: OpCode,
     arg1: S,
     arg2: Option<S>,
@@ -52,7 +109,22 @@ pub(crate) fn first_order_value<S: Float>(
         OpCode::Add => darg1 + darg2.unwrap(),
         OpCode::Sub => darg1 - darg2.unwrap(),
         OpCode::Mul => darg1 * arg2.unwrap() + arg1 * darg2.unwrap(),
-        OpCode::Div => (darg1 * arg2.unwrap() - arg1 * darg2.unwrap()) / arg2.unwrap().powi(2),
+        OpCode::Div => {
+            let b = arg2.unwrap();
+            (darg1 * b - arg1 * darg2.unwrap()) / (b * b)
+        }
+        _ => panic!("Invalid opcode in first_order_value_scalar"),
+    }
+}
+
+pub(crate) fn first_order_value<S: Transcendental>(
+    opcode: OpCode,
+    arg1: S,
+    arg2: Option<S>,
+    darg1: S,
+    darg2: Option<S>,
+) -> S {
+    match opcode {
         OpCode::Sin => darg1 * arg1.cos(),
         OpCode::Cos => -darg1 * arg1.sin(),
         OpCode::Tan => darg1 * (S::one() / arg1.cos().powi(2)),
@@ -80,12 +152,55 @@ pub(crate) fn first_order_value<S: Float>(
             };
             rv1 + rv2
         }
-        _ => panic!("Invalid opcode in first_order_value"),
+        OpCode::Sinh => darg1 * arg1.cosh(),
+        OpCode::Cosh => darg1 * arg1.sinh(),
+        OpCode::Tanh => darg1 * (S::one() - arg1.tanh().powi(2)),
+        OpCode::Asinh => darg1 / (arg1.powi(2) + S::one()).sqrt(),
+        OpCode::Acosh => darg1 / (arg1.powi(2) - S::one()).sqrt(),
+        OpCode::Atanh => darg1 / (S::one() - arg1.powi(2)),
+        OpCode::ExpM1 => darg1 * arg1.exp(),
+        OpCode::Ln1p => darg1 / (S::one() + arg1),
+        OpCode::Atan2 => {
+            let y = arg1;
+            let x = arg2.unwrap();
+            let dy = darg1;
+            let dx = darg2.unwrap();
+            (x * dy - y * dx) / (x.powi(2) + y.powi(2))
+        }
+        // Piecewise-constant almost everywhere, so the a.e. derivative is taken at the kinks too
+        // (matching `Abs`'s convention of being differentiable from the right there)
+        OpCode::Floor | OpCode::Ceil | OpCode::Round | OpCode::Trunc | OpCode::Signum => {
+            S::zero()
+        }
+        OpCode::Fract => darg1,
+        // The active branch's tangent, with the tie at the kink resolved the same way `Abs`'s own
+        // rule resolves it (`(arg1+darg1).abs() - arg1.abs()`), since `max`/`min`/`relu` are
+        // exactly that rule applied to `arg1 - arg2` (or `arg1` itself for `relu`).
+        OpCode::Min => {
+            let two = S::one() + S::one();
+            let diff = arg1 - arg2.unwrap();
+            let ddiff = darg1 - darg2.unwrap();
+            let dabs = (diff + ddiff).abs() - diff.abs();
+            (darg1 + darg2.unwrap() - dabs) / two
+        }
+        OpCode::Max => {
+            let two = S::one() + S::one();
+            let diff = arg1 - arg2.unwrap();
+            let ddiff = darg1 - darg2.unwrap();
+            let dabs = (diff + ddiff).abs() - diff.abs();
+            (darg1 + darg2.unwrap() + dabs) / two
+        }
+        OpCode::Relu => {
+            let two = S::one() + S::one();
+            let dabs = (arg1 + darg1).abs() - arg1.abs();
+            (darg1 + dabs) / two
+        }
+        _ => first_order_value_scalar(opcode, arg1, arg2, darg1, darg2),
     }
 }
 
 /// Representation of a single elementary operation and inputs and output
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
     /// Op code of the operation
     pub opcode: OpCode,
@@ -242,7 +357,173 @@ impl Operation {
         }
     }
 
-    pub fn zero_order(self, v: &mut [f64]) {
+    pub fn sinh(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Sinh,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn cosh(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Cosh,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn tanh(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Tanh,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn asinh(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Asinh,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn acosh(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Acosh,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn atanh(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Atanh,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn exp_m1(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::ExpM1,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn ln_1p(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Ln1p,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn atan2(vid: usize, y: usize, x: usize) -> Self {
+        Self {
+            opcode: OpCode::Atan2,
+            vid,
+            arg1: Some(y),
+            arg2: Some(x),
+        }
+    }
+
+    pub fn floor(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Floor,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn ceil(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Ceil,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn round(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Round,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn trunc(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Trunc,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn fract(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Fract,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn signum(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Signum,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    pub fn min(vid: usize, lhs: usize, rhs: usize) -> Self {
+        Self {
+            opcode: OpCode::Min,
+            vid,
+            arg1: Some(lhs),
+            arg2: Some(rhs),
+        }
+    }
+
+    pub fn max(vid: usize, lhs: usize, rhs: usize) -> Self {
+        Self {
+            opcode: OpCode::Max,
+            vid,
+            arg1: Some(lhs),
+            arg2: Some(rhs),
+        }
+    }
+
+    pub fn relu(vid: usize, idx: usize) -> Self {
+        Self {
+            opcode: OpCode::Relu,
+            vid,
+            arg1: Some(idx),
+            arg2: None,
+        }
+    }
+
+    /// Re-evaluate this operation, reading its arguments and writing its result into `v`
+    ///
+    /// Generic over any [`Transcendental`] scalar -- [`Scalar`]-only types cannot appear here
+    /// since a tape may record any `OpCode`, including the transcendental ones.
+    pub fn zero_order<S: Transcendental>(self, v: &mut [S]) {
         match self.opcode {
             OpCode::Nop => {}
             OpCode::Const => {}
@@ -253,11 +534,11 @@ impl Operation {
         }
     }
 
-    pub fn first_order(self, v: &[f64], dv: &mut [f64]) {
+    pub fn first_order<S: Transcendental>(self, v: &[S], dv: &mut [S]) {
         match self.opcode {
             OpCode::Nop => {}
             OpCode::Const => {
-                dv[self.vid] = 0.0;
+                dv[self.vid] = S::zero();
             }
             _ => {
                 dv[self.vid] = first_order_value(
@@ -271,7 +552,7 @@ impl Operation {
         }
     }
 
-    pub fn first_order_reverse(self, v: &[f64], vbar: &mut [f64]) {
+    pub fn first_order_reverse<S: Transcendental>(self, v: &[S], vbar: &mut [S]) {
         // ∂s/∂v_i = sum_j ∂s/∂v_j * ∂v_j/∂v_i  + ...
         // vbar_i := ∂s/∂v_i
         // => vbar_i = sum_j vbar_j * ∂v_j/∂v_i
@@ -307,7 +588,7 @@ impl Operation {
                 // =>
                 // vbar_j += vbar_i * ∂v_i/∂v_j = vbar_i * 1/v_k
                 // vbar_k += vbar_i * ∂v_i/∂v_k = vbar_i * -v_j/(v_k^2)
-                vbar[self.arg1.unwrap()] += vbar[self.vid] * 1.0 / v[self.arg2.unwrap()];
+                vbar[self.arg1.unwrap()] += vbar[self.vid] * S::one() / v[self.arg2.unwrap()];
                 vbar[self.arg2.unwrap()] +=
                     vbar[self.vid] * (-v[self.arg1.unwrap()] / v[self.arg2.unwrap()].powi(2));
             }
@@ -318,17 +599,51 @@ impl Operation {
                 // vbar_k += vbar_i * ∂v_i/∂v_k = vbar_i * v_j.ln() * v_j.powf(v_k)
                 let x = v[self.arg1.unwrap()];
                 let y = v[self.arg2.unwrap()];
-                vbar[self.arg1.unwrap()] += vbar[self.vid] * y * x.powf(y - 1.0);
+                vbar[self.arg1.unwrap()] += vbar[self.vid] * y * x.powf(y - S::one());
                 vbar[self.arg2.unwrap()] += vbar[self.vid] * x.ln() * x.powf(y);
             }
             OpCode::Abs => {
                 panic!("Abs-function encountered in first_order_reverse");
             }
+            OpCode::Max => {
+                // v_i = max(v_j, v_k) = (v_j+v_k+|v_j-v_k|)/2
+                // Unlike `Abs`, fixed to the value's own sign at the tie (matching `Signum`'s
+                // convention) instead of panicking, so this can be folded into a plain reverse
+                // sweep the same way the smooth opcodes above are.
+                let two = S::one() + S::one();
+                let sigma = (v[self.arg1.unwrap()] - v[self.arg2.unwrap()]).signum();
+                vbar[self.arg1.unwrap()] += vbar[self.vid] * (S::one() + sigma) / two;
+                vbar[self.arg2.unwrap()] += vbar[self.vid] * (S::one() - sigma) / two;
+            }
+            OpCode::Min => {
+                // v_i = min(v_j, v_k) = (v_j+v_k-|v_j-v_k|)/2
+                let two = S::one() + S::one();
+                let sigma = (v[self.arg1.unwrap()] - v[self.arg2.unwrap()]).signum();
+                vbar[self.arg1.unwrap()] += vbar[self.vid] * (S::one() - sigma) / two;
+                vbar[self.arg2.unwrap()] += vbar[self.vid] * (S::one() + sigma) / two;
+            }
+            OpCode::Relu => {
+                // v_i = relu(v_j) = max(v_j, 0), with the tie resolved as in `Max`
+                let two = S::one() + S::one();
+                let sigma = v[self.arg1.unwrap()].signum();
+                vbar[self.arg1.unwrap()] += vbar[self.vid] * (S::one() + sigma) / two;
+            }
+            OpCode::Atan2 => {
+                // v_i = atan2(v_j, v_k)
+                // =>
+                // vbar_j += vbar_i * ∂v_i/∂v_j = vbar_i * v_k/(v_j^2+v_k^2)
+                // vbar_k += vbar_i * ∂v_i/∂v_k = vbar_i * -v_j/(v_j^2+v_k^2)
+                let y = v[self.arg1.unwrap()];
+                let x = v[self.arg2.unwrap()];
+                let denom = x.powi(2) + y.powi(2);
+                vbar[self.arg1.unwrap()] += vbar[self.vid] * x / denom;
+                vbar[self.arg2.unwrap()] += vbar[self.vid] * (-y / denom);
+            }
             _ => {
                 // Unary function
                 // vbar_j += vbar_i * ∂v_i/∂v_j
                 vbar[self.arg1.unwrap()] += vbar[self.vid]
-                    * first_order_value(self.opcode, v[self.arg1.unwrap()], None, 1.0, None);
+                    * first_order_value(self.opcode, v[self.arg1.unwrap()], None, S::one(), None);
             }
         }
     }
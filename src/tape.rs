@@ -1,9 +1,14 @@
 use super::*;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::io::{self, Read, Write};
 use std::iter::{DoubleEndedIterator, Iterator};
 
 /// Evaluation procedure and intermediate values of a function evaluation
-pub trait Tape<S: Float + 'static>: Send + Sync + fmt::Debug {
+pub trait Tape<S: Scalar + 'static>: Send + Sync + fmt::Debug {
     /// Independent variable indices
     fn indeps(&self) -> &[usize];
     /// Dependent variable indices
@@ -17,7 +22,7 @@ pub trait Tape<S: Float + 'static>: Send + Sync + fmt::Debug {
 }
 
 /// Extra functions on a tape
-pub trait TapeExt<S: Float + 'static> {
+pub trait TapeExt<S: Scalar + 'static> {
     /// Number of independents
     fn num_indeps(&self) -> usize;
 
@@ -41,22 +46,84 @@ pub trait TapeExt<S: Float + 'static> {
         S: fmt::Debug;
 
     /// Re-evaluate function from stored evaluation procedure
+    ///
+    /// Requires [`Transcendental`] rather than just [`Scalar`] since a tape may record any
+    /// `OpCode`, including the transcendental ones.
     fn zero_order(&mut self, x: &DVector<S>)
     where
-        S: fmt::Debug;
+        S: Transcendental;
 
     /// Calculate adjoint of Jacobian
     fn first_order_forward(&self, dx: &DVector<S>) -> DVector<S>
     where
-        S: fmt::Debug;
+        S: Transcendental;
 
     /// Calculate reverse-adjoint of Jacobian
     fn first_order_reverse(&self, ybar: &DVector<S>) -> DVector<S>
     where
-        S: fmt::Debug;
+        S: Transcendental;
+
+    /// Reverse sweep seeded at a single value id, independent of the declared [`Tape::deps`]
+    ///
+    /// Used by [`AFloat::grad`] to differentiate one recorded value directly from its `val_id`,
+    /// without requiring that value to have been registered as a dependent via
+    /// [`AContext::set_dep`].
+    fn first_order_reverse_at(&self, vid: usize) -> DVector<S>
+    where
+        S: Transcendental;
+
+    /// Serialize this tape to `w`, so it can be reloaded later with [`SerializedTape::load`]
+    fn save<W: Write>(&self, w: W) -> io::Result<()>
+    where
+        S: Serialize;
+
+    /// [`TapeExt::first_order_forward`] for a whole batch of tangent directions at once
+    ///
+    /// One column per direction, `dx.nrows() == num_indeps()`. Unlike calling
+    /// [`TapeExt::first_order_forward`] once per column, the operation stream is only materialized
+    /// from [`Tape::ops_iter`] once and then replayed per column, and the columns are swept across
+    /// rayon's global pool -- each gets its own tangent buffer, reading the shared [`Tape::values`]
+    /// slice only.
+    fn first_order_forward_batch(&self, dx: &DMatrix<S>) -> DMatrix<S>
+    where
+        S: Transcendental;
+
+    /// [`TapeExt::first_order_reverse`] for a whole batch of adjoint directions at once
+    ///
+    /// One column in, one column out: `ybar.nrows() == num_deps()` and the result has
+    /// `num_indeps()` rows, one column per adjoint direction -- so a full Jacobian needs the
+    /// result transposed, same as chaining single-direction [`TapeExt::first_order_reverse`]
+    /// calls into rows would. Materializes [`Tape::ops_iter`] once, like
+    /// [`TapeExt::first_order_forward_batch`], and sweeps columns across rayon's global pool.
+    fn first_order_reverse_batch(&self, ybar: &DMatrix<S>) -> DMatrix<S>
+    where
+        S: Transcendental;
+
+    /// Full Jacobian, computed with whichever of [`TapeExt::first_order_forward_batch`] /
+    /// [`TapeExt::first_order_reverse_batch`] needs fewer sweeps for this tape's shape
+    fn jacobian(&self) -> DMatrix<S>
+    where
+        S: Transcendental;
+
+    /// Hessian-vector product `H*dx` of `ybar^T y` at the tape's current point, computed
+    /// forward-over-reverse
+    ///
+    /// Rather than giving every `OpCode` its own second-derivative formula, this differentiates a
+    /// whole reverse sweep through [`AFloat`]'s forward-mode dual numbers: the primal sweep is
+    /// replayed with each value carrying its directional derivative along `dx`, then the reverse
+    /// sweep runs on those duals, seeded from `ybar` with no tangent of its own. The reverse
+    /// sweep's own adjoint equations are exactly `∂/∂dx` of [`TapeExt::first_order_reverse`]'s, so
+    /// the dual part that falls out at the independents is `H*dx` for free.
+    ///
+    /// Returns `(gradient, hvp)`: `gradient` is the same `J^T ybar` a plain
+    /// [`TapeExt::first_order_reverse`] call would produce, `hvp` is that gradient differentiated
+    /// once more along `dx`.
+    fn second_order_reverse(&self, dx: &DVector<S>, ybar: &DVector<S>) -> (DVector<S>, DVector<S>)
+    where
+        S: Transcendental;
 }
 
-impl<T, S: Float + 'static> TapeExt<S> for T
+impl<T, S: Scalar + 'static> TapeExt<S> for T
 where
     T: Tape<S> + ?Sized,
 {
@@ -105,7 +172,7 @@ where
 
     fn zero_order(&mut self, x: &DVector<S>)
     where
-        S: fmt::Debug,
+        S: Transcendental,
     {
         assert_eq!(x.nrows(), self.num_indeps());
         let indeps = self.indeps().to_vec();
@@ -121,7 +188,7 @@ where
 
     fn first_order_forward(&self, dx: &DVector<S>) -> DVector<S>
     where
-        S: fmt::Debug,
+        S: Transcendental,
     {
         let v = self.values();
         let mut dv = vec![S::zero(); v.len()];
@@ -140,7 +207,7 @@ where
 
     fn first_order_reverse(&self, ybar: &DVector<S>) -> DVector<S>
     where
-        S: fmt::Debug,
+        S: Transcendental,
     {
         let v = self.values();
         let mut vbar = vec![S::zero(); v.len()];
@@ -156,6 +223,521 @@ where
         }
         xbar
     }
+
+    fn first_order_reverse_at(&self, vid: usize) -> DVector<S>
+    where
+        S: Transcendental,
+    {
+        let v = self.values();
+        let mut vbar = vec![S::zero(); v.len()];
+        vbar[vid] = S::one();
+        for op in self.ops_iter().rev() {
+            op.first_order_reverse(v, &mut vbar);
+        }
+        let mut xbar = DVector::zeros(self.num_indeps());
+        for (idx, vid) in self.indeps().iter().enumerate() {
+            xbar[idx] = vbar[*vid];
+        }
+        xbar
+    }
+
+    fn save<W: Write>(&self, w: W) -> io::Result<()>
+    where
+        S: Serialize,
+    {
+        SerializedTape::from_tape(self).save(w)
+    }
+
+    fn first_order_forward_batch(&self, dx: &DMatrix<S>) -> DMatrix<S>
+    where
+        S: Transcendental,
+    {
+        let n = self.num_indeps();
+        let m = self.num_deps();
+        assert_eq!(dx.nrows(), n);
+        let k = dx.ncols();
+
+        let v = self.values();
+        let ops: Vec<Operation> = self.ops_iter().collect();
+        let indeps = self.indeps();
+        let deps = self.deps();
+
+        let columns: Vec<DVector<S>> = (0..k)
+            .into_par_iter()
+            .map(|col| {
+                let mut dv = vec![S::zero(); v.len()];
+                for (idx, vid) in indeps.iter().enumerate() {
+                    dv[*vid] = dx[(idx, col)];
+                }
+                for op in &ops {
+                    op.first_order(v, &mut dv);
+                }
+                let mut dy = DVector::zeros(m);
+                for (idx, vid) in deps.iter().enumerate() {
+                    dy[idx] = dv[*vid];
+                }
+                dy
+            })
+            .collect();
+
+        let mut jacobian = DMatrix::from_element(m, k, S::zero());
+        for (col, dy) in columns.into_iter().enumerate() {
+            for i in 0..m {
+                jacobian[(i, col)] = dy[i];
+            }
+        }
+        jacobian
+    }
+
+    fn first_order_reverse_batch(&self, ybar: &DMatrix<S>) -> DMatrix<S>
+    where
+        S: Transcendental,
+    {
+        let n = self.num_indeps();
+        let m = self.num_deps();
+        assert_eq!(ybar.nrows(), m);
+        let k = ybar.ncols();
+
+        let v = self.values();
+        let ops: Vec<Operation> = self.ops_iter().collect();
+        let indeps = self.indeps();
+        let deps = self.deps();
+
+        let columns: Vec<DVector<S>> = (0..k)
+            .into_par_iter()
+            .map(|col| {
+                let mut vbar = vec![S::zero(); v.len()];
+                for (idx, vid) in deps.iter().enumerate() {
+                    vbar[*vid] = ybar[(idx, col)];
+                }
+                for op in ops.iter().rev() {
+                    op.first_order_reverse(v, &mut vbar);
+                }
+                let mut xbar = DVector::zeros(n);
+                for (idx, vid) in indeps.iter().enumerate() {
+                    xbar[idx] = vbar[*vid];
+                }
+                xbar
+            })
+            .collect();
+
+        let mut result = DMatrix::from_element(n, k, S::zero());
+        for (col, xbar) in columns.into_iter().enumerate() {
+            for j in 0..n {
+                result[(j, col)] = xbar[j];
+            }
+        }
+        result
+    }
+
+    fn jacobian(&self) -> DMatrix<S>
+    where
+        S: Transcendental,
+    {
+        let n = self.num_indeps();
+        let m = self.num_deps();
+        if n <= m {
+            let mut dx = DMatrix::from_element(n, n, S::zero());
+            for i in 0..n {
+                dx[(i, i)] = S::one();
+            }
+            self.first_order_forward_batch(&dx)
+        } else {
+            let mut dy = DMatrix::from_element(m, m, S::zero());
+            for i in 0..m {
+                dy[(i, i)] = S::one();
+            }
+            self.first_order_reverse_batch(&dy).transpose()
+        }
+    }
+
+    fn second_order_reverse(&self, dx: &DVector<S>, ybar: &DVector<S>) -> (DVector<S>, DVector<S>)
+    where
+        S: Transcendental,
+    {
+        let n = self.num_indeps();
+        assert_eq!(dx.nrows(), n);
+        assert_eq!(ybar.nrows(), self.num_deps());
+
+        let v = self.values();
+        let ops: Vec<Operation> = self.ops_iter().collect();
+        let indeps = self.indeps();
+        let deps = self.deps();
+
+        let mut vdual: Vec<AFloat<S>> = v.iter().map(|x| AFloat::new(*x, S::zero())).collect();
+        for (idx, vid) in indeps.iter().enumerate() {
+            vdual[*vid] = AFloat::new(v[*vid], dx[idx]);
+        }
+        for op in &ops {
+            op.zero_order(&mut vdual);
+        }
+
+        let mut vbar = vec![AFloat::new(S::zero(), S::zero()); vdual.len()];
+        for (idx, vid) in deps.iter().enumerate() {
+            vbar[*vid] = AFloat::new(ybar[idx], S::zero());
+        }
+        for op in ops.iter().rev() {
+            op.first_order_reverse(&vdual, &mut vbar);
+        }
+
+        let mut gradient = DVector::zeros(n);
+        let mut hvp = DVector::zeros(n);
+        for (idx, vid) in indeps.iter().enumerate() {
+            gradient[idx] = vbar[*vid].value();
+            hvp[idx] = vbar[*vid].dvalue();
+        }
+        (gradient, hvp)
+    }
+}
+
+/// Version tag written ahead of the bincode-encoded payload; bump whenever the wire format of
+/// [`SerializedTape`] changes
+const TAPE_FORMAT_VERSION: u32 = 1;
+
+/// A plain-data snapshot of a [`Tape`], serializable with `serde` and persistable to disk
+///
+/// Unlike a live tape bound to an [`AContext`], `SerializedTape` owns its data directly and
+/// implements [`Tape`] itself, so it can be written once with [`SerializedTape::save`] and
+/// reloaded cheaply for repeated gradient/Jacobian evaluations with [`SerializedTape::load`]
+/// instead of re-taping the function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedTape<S> {
+    indeps: Vec<usize>,
+    deps: Vec<usize>,
+    ops: Vec<Operation>,
+    vals: Vec<S>,
+}
+
+impl<S: Scalar + 'static> SerializedTape<S> {
+    /// Snapshot any tape into a serializable, owned form
+    pub fn from_tape(tape: &dyn Tape<S>) -> Self {
+        Self {
+            indeps: tape.indeps().to_vec(),
+            deps: tape.deps().to_vec(),
+            ops: tape.ops_iter().collect(),
+            vals: tape.values().to_vec(),
+        }
+    }
+
+    /// Write this tape to `w`, preceded by a version header
+    pub fn save<W: Write>(&self, mut w: W) -> io::Result<()>
+    where
+        S: Serialize,
+    {
+        w.write_u32::<LittleEndian>(TAPE_FORMAT_VERSION)?;
+        bincode::serialize_into(w, self).map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+    }
+
+    /// Read a tape previously written with [`SerializedTape::save`]
+    ///
+    /// Validates the version header up front and that every operation only references value
+    /// indices within the loaded `vals` buffer, so a corrupt file is rejected cleanly instead of
+    /// panicking mid-sweep.
+    pub fn load<R: Read>(mut r: R) -> io::Result<Self>
+    where
+        S: DeserializeOwned,
+    {
+        let version = r.read_u32::<LittleEndian>()?;
+        if version != TAPE_FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "unsupported tape format version {} (expected {})",
+                    version, TAPE_FORMAT_VERSION
+                ),
+            ));
+        }
+
+        let this: Self = bincode::deserialize_from(r)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let nvals = this.vals.len();
+        let in_bounds = |idx: Option<usize>| idx.map_or(true, |idx| idx < nvals);
+        for op in &this.ops {
+            if op.vid >= nvals || !in_bounds(op.arg1) || !in_bounds(op.arg2) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "tape operation references an out-of-bounds value index",
+                ));
+            }
+        }
+
+        Ok(this)
+    }
+}
+
+impl<S: Scalar + 'static> Tape<S> for SerializedTape<S> {
+    fn indeps(&self) -> &[usize] {
+        &self.indeps
+    }
+
+    fn deps(&self) -> &[usize] {
+        &self.deps
+    }
+
+    fn values(&self) -> &[S] {
+        &self.vals
+    }
+
+    fn values_mut(&mut self) -> &mut [S] {
+        &mut self.vals
+    }
+
+    fn ops_iter<'a>(&'a self) -> Box<dyn DoubleEndedIterator<Item = Operation> + 'a> {
+        Box::new(self.ops.iter().cloned())
+    }
+}
+
+impl SerializedTape<f64> {
+    /// Write this tape as whitespace-separated decimal tokens instead of [`SerializedTape::save`]'s
+    /// binary format
+    ///
+    /// Emits each list (`indeps`, `deps`, `vals`, `ops`) in turn as its length followed by its
+    /// elements, one token at a time -- no delimiters beyond whitespace are significant, so the output reads
+    /// equally well newline- or space-separated. Meant for sending a tape across a text-oriented
+    /// boundary (a pipe, an FFI caller without access to `bincode`) where [`SerializedTape::load_text`]
+    /// is the matching reader.
+    pub fn save_text<W: Write>(&self, mut w: W) -> io::Result<()> {
+        writeln!(w, "{}", self.indeps.len())?;
+        for vid in &self.indeps {
+            write!(w, "{} ", vid)?;
+        }
+        writeln!(w)?;
+
+        writeln!(w, "{}", self.deps.len())?;
+        for vid in &self.deps {
+            write!(w, "{} ", vid)?;
+        }
+        writeln!(w)?;
+
+        writeln!(w, "{}", self.vals.len())?;
+        for val in &self.vals {
+            write!(w, "{} ", val)?;
+        }
+        writeln!(w)?;
+
+        writeln!(w, "{}", self.ops.len())?;
+        for op in &self.ops {
+            writeln!(
+                w,
+                "{} {} {} {}",
+                opcode_to_token(op.opcode),
+                op.vid,
+                arg_to_token(op.arg1),
+                arg_to_token(op.arg2),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Read a tape previously written with [`SerializedTape::save_text`]
+    ///
+    /// Reads the whole stream up front and hands out tokens from it one at a time, mirroring the
+    /// lightweight token-scanner pattern used for whitespace-separated input elsewhere in the Rust
+    /// ecosystem, rather than parsing the stream line-by-line. Validates value indices the same way
+    /// [`SerializedTape::load`] does, so a malformed stream is rejected instead of panicking mid-sweep.
+    pub fn load_text<R: Read>(r: R) -> io::Result<Self> {
+        let mut scanner = TokenScanner::new(r)?;
+
+        let n_indeps = scanner.next_usize()?;
+        let indeps = (0..n_indeps)
+            .map(|_| scanner.next_usize())
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let n_deps = scanner.next_usize()?;
+        let deps = (0..n_deps)
+            .map(|_| scanner.next_usize())
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let n_vals = scanner.next_usize()?;
+        let vals = (0..n_vals)
+            .map(|_| scanner.next_f64())
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let n_ops = scanner.next_usize()?;
+        let ops = (0..n_ops)
+            .map(|_| -> io::Result<Operation> {
+                let opcode = opcode_from_token(&scanner.next_token()?)?;
+                let vid = scanner.next_usize()?;
+                let arg1 = scanner.next_arg()?;
+                let arg2 = scanner.next_arg()?;
+                Ok(Operation {
+                    opcode,
+                    vid,
+                    arg1,
+                    arg2,
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let this = Self {
+            indeps,
+            deps,
+            ops,
+            vals,
+        };
+
+        let nvals = this.vals.len();
+        let in_bounds = |idx: Option<usize>| idx.map_or(true, |idx| idx < nvals);
+        for op in &this.ops {
+            if op.vid >= nvals || !in_bounds(op.arg1) || !in_bounds(op.arg2) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "tape operation references an out-of-bounds value index",
+                ));
+            }
+        }
+
+        Ok(this)
+    }
+}
+
+/// Token used in place of an actual index to mark an absent `arg1`/`arg2` in the text tape format
+const NO_ARG_TOKEN: &str = "-";
+
+fn arg_to_token(arg: Option<usize>) -> String {
+    match arg {
+        Some(idx) => idx.to_string(),
+        None => NO_ARG_TOKEN.to_string(),
+    }
+}
+
+fn opcode_to_token(opcode: OpCode) -> &'static str {
+    match opcode {
+        OpCode::Nop => "Nop",
+        OpCode::Const => "Const",
+        OpCode::Add => "Add",
+        OpCode::Sub => "Sub",
+        OpCode::Mul => "Mul",
+        OpCode::Div => "Div",
+        OpCode::Sin => "Sin",
+        OpCode::Cos => "Cos",
+        OpCode::Tan => "Tan",
+        OpCode::Abs => "Abs",
+        OpCode::Exp => "Exp",
+        OpCode::Ln => "Ln",
+        OpCode::Asin => "Asin",
+        OpCode::Acos => "Acos",
+        OpCode::Atan => "Atan",
+        OpCode::Powf => "Powf",
+        OpCode::Sinh => "Sinh",
+        OpCode::Cosh => "Cosh",
+        OpCode::Tanh => "Tanh",
+        OpCode::Asinh => "Asinh",
+        OpCode::Acosh => "Acosh",
+        OpCode::Atanh => "Atanh",
+        OpCode::ExpM1 => "ExpM1",
+        OpCode::Ln1p => "Ln1p",
+        OpCode::Atan2 => "Atan2",
+        OpCode::Floor => "Floor",
+        OpCode::Ceil => "Ceil",
+        OpCode::Round => "Round",
+        OpCode::Trunc => "Trunc",
+        OpCode::Fract => "Fract",
+        OpCode::Signum => "Signum",
+        OpCode::Min => "Min",
+        OpCode::Max => "Max",
+        OpCode::Relu => "Relu",
+    }
+}
+
+fn opcode_from_token(token: &str) -> io::Result<OpCode> {
+    Ok(match token {
+        "Nop" => OpCode::Nop,
+        "Const" => OpCode::Const,
+        "Add" => OpCode::Add,
+        "Sub" => OpCode::Sub,
+        "Mul" => OpCode::Mul,
+        "Div" => OpCode::Div,
+        "Sin" => OpCode::Sin,
+        "Cos" => OpCode::Cos,
+        "Tan" => OpCode::Tan,
+        "Abs" => OpCode::Abs,
+        "Exp" => OpCode::Exp,
+        "Ln" => OpCode::Ln,
+        "Asin" => OpCode::Asin,
+        "Acos" => OpCode::Acos,
+        "Atan" => OpCode::Atan,
+        "Powf" => OpCode::Powf,
+        "Sinh" => OpCode::Sinh,
+        "Cosh" => OpCode::Cosh,
+        "Tanh" => OpCode::Tanh,
+        "Asinh" => OpCode::Asinh,
+        "Acosh" => OpCode::Acosh,
+        "Atanh" => OpCode::Atanh,
+        "ExpM1" => OpCode::ExpM1,
+        "Ln1p" => OpCode::Ln1p,
+        "Atan2" => OpCode::Atan2,
+        "Floor" => OpCode::Floor,
+        "Ceil" => OpCode::Ceil,
+        "Round" => OpCode::Round,
+        "Trunc" => OpCode::Trunc,
+        "Fract" => OpCode::Fract,
+        "Signum" => OpCode::Signum,
+        "Min" => OpCode::Min,
+        "Max" => OpCode::Max,
+        "Relu" => OpCode::Relu,
+        other => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unrecognized opcode token '{}'", other),
+            ))
+        }
+    })
+}
+
+/// Reads an entire stream up front and hands out whitespace-separated tokens from it one at a time
+///
+/// The "read everything, split on whitespace, pull tokens as needed" pattern used here is the same
+/// lightweight scanner idiom commonly reached for when parsing whitespace-delimited text input.
+struct TokenScanner {
+    tokens: std::vec::IntoIter<String>,
+}
+
+impl TokenScanner {
+    fn new<R: Read>(mut r: R) -> io::Result<Self> {
+        let mut buf = String::new();
+        r.read_to_string(&mut buf)?;
+        let tokens: Vec<String> = buf.split_whitespace().map(str::to_owned).collect();
+        Ok(Self {
+            tokens: tokens.into_iter(),
+        })
+    }
+
+    fn next_token(&mut self) -> io::Result<String> {
+        self.tokens.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "unexpected end of tape token stream",
+            )
+        })
+    }
+
+    fn next_usize(&mut self) -> io::Result<usize> {
+        self.next_token()?
+            .parse()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "expected an integer token"))
+    }
+
+    fn next_f64(&mut self) -> io::Result<f64> {
+        self.next_token()?.parse().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "expected a floating point token",
+            )
+        })
+    }
+
+    fn next_arg(&mut self) -> io::Result<Option<usize>> {
+        let token = self.next_token()?;
+        if token == NO_ARG_TOKEN {
+            Ok(None)
+        } else {
+            token.parse().map(Some).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidData, "expected an integer token")
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -237,6 +819,122 @@ mod tests {
         };
     }
 
+    /// A tape survives a save/load round-trip through an in-memory buffer
+    #[test]
+    fn save_load_round_trip() {
+        let tape = adv_fn_obj!(all_arithmetic_test_func).tape(&DVector::from_element(1, 3.0));
+
+        let mut buf = Vec::new();
+        tape.save(&mut buf).unwrap();
+
+        let loaded = SerializedTape::<f64>::load(buf.as_slice()).unwrap();
+        assert_eq!(loaded.indeps(), tape.indeps());
+        assert_eq!(loaded.deps(), tape.deps());
+        assert_eq!(loaded.values(), tape.values());
+
+        let dy = loaded.first_order_forward(&DVector::from_element(1, 1.0));
+        assert!((dy[0] - 1.0).abs() < std::f64::EPSILON);
+    }
+
+    /// A loaded tape reproduces `first_order_reverse` bit-for-bit, not just within tolerance --
+    /// it replays the exact stored `f64` values through the exact recorded op stream, so nothing
+    /// about the round-trip should perturb the result
+    #[test]
+    fn save_load_round_trip_reverse_bit_for_bit() {
+        let tape = adv_fn_obj!(all_arithmetic_test_func).tape(&DVector::from_element(1, 3.0));
+        let expected = tape.first_order_reverse(&DVector::from_element(1, 1.0));
+
+        let mut buf = Vec::new();
+        tape.save(&mut buf).unwrap();
+        let loaded = SerializedTape::<f64>::load(buf.as_slice()).unwrap();
+        let actual = loaded.first_order_reverse(&DVector::from_element(1, 1.0));
+
+        assert_eq!(actual, expected);
+    }
+
+    /// Loading a tape with a corrupt value index fails cleanly instead of panicking
+    #[test]
+    fn load_rejects_out_of_bounds_operation() {
+        let corrupt = SerializedTape::<f64> {
+            indeps: vec![0],
+            deps: vec![1],
+            ops: vec![Operation {
+                opcode: OpCode::Sin,
+                vid: 1,
+                arg1: Some(42),
+                arg2: None,
+            }],
+            vals: vec![0.0, 0.0],
+        };
+
+        let mut buf = Vec::new();
+        corrupt.save(&mut buf).unwrap();
+
+        assert!(SerializedTape::<f64>::load(buf.as_slice()).is_err());
+    }
+
+    /// A tape survives a save_text/load_text round-trip through an in-memory buffer
+    #[test]
+    fn save_load_text_round_trip() {
+        let tape = adv_fn_obj!(all_arithmetic_test_func).tape(&DVector::from_element(1, 3.0));
+
+        let mut buf = Vec::new();
+        SerializedTape::from_tape(&tape)
+            .save_text(&mut buf)
+            .unwrap();
+
+        let loaded = SerializedTape::<f64>::load_text(buf.as_slice()).unwrap();
+        assert_eq!(loaded.indeps(), tape.indeps());
+        assert_eq!(loaded.deps(), tape.deps());
+        assert_eq!(loaded.values(), tape.values());
+
+        let dy = loaded.first_order_forward(&DVector::from_element(1, 1.0));
+        assert!((dy[0] - 1.0).abs() < std::f64::EPSILON);
+    }
+
+    /// The text format round-trips a tape using the new Min/Max/Relu opcodes as well
+    #[test]
+    fn save_load_text_round_trip_min_max_relu() {
+        let tape = SerializedTape::<f64> {
+            indeps: vec![0, 1],
+            deps: vec![4],
+            ops: vec![
+                Operation::max(2, 0, 1),
+                Operation::min(3, 0, 1),
+                Operation::relu(4, 3),
+            ],
+            vals: vec![2.0, -1.0, 0.0, 0.0, 0.0],
+        };
+
+        let mut buf = Vec::new();
+        tape.save_text(&mut buf).unwrap();
+
+        let mut loaded = SerializedTape::<f64>::load_text(buf.as_slice()).unwrap();
+        loaded.zero_order(&DVector::from_vec(vec![2.0, -1.0]));
+        assert_eq!(loaded.y()[0], 0.0);
+    }
+
+    /// Loading a text tape with a corrupt value index fails cleanly instead of panicking
+    #[test]
+    fn load_text_rejects_out_of_bounds_operation() {
+        let corrupt = SerializedTape::<f64> {
+            indeps: vec![0],
+            deps: vec![1],
+            ops: vec![Operation {
+                opcode: OpCode::Sin,
+                vid: 1,
+                arg1: Some(42),
+                arg2: None,
+            }],
+            vals: vec![0.0, 0.0],
+        };
+
+        let mut buf = Vec::new();
+        corrupt.save_text(&mut buf).unwrap();
+
+        assert!(SerializedTape::<f64>::load_text(buf.as_slice()).is_err());
+    }
+
     /// Forward-mode and reverse-mode work on nonlinear unary functions
     #[test]
     #[allow(clippy::redundant_closure_call)]
@@ -252,4 +950,104 @@ mod tests {
         unary_test_case!(acos, |x: f64| -1.0 / (1.0 - x.powi(2)).sqrt());
         unary_test_case!(atan, |x: f64| 1.0 / (1.0 + x.powi(2)));
     }
+
+    adv_fn! {
+        fn polar_to_cartesian(input: [[2]]) -> [[2]] {
+            let r = input[0];
+            let phi = input[1];
+            adv_dvec![r * phi.cos(), r * phi.sin()]
+        }
+    }
+
+    fn polar_to_cartesian_tape(polar: &DVector<f64>) -> impl Tape<f64> {
+        let mut ctx = AContext::new();
+        let input = DVector::from_vec(ctx.new_indep_vec(2, 0.0));
+        let output = polar_to_cartesian(input);
+        ctx.set_dep_slice(output.as_slice());
+        let mut tape = ctx.tape();
+        tape.zero_order(polar);
+        tape
+    }
+
+    fn polar_reference_jacobian(polar: &DVector<f64>) -> DMatrix<f64> {
+        let r = polar[0];
+        let phi = polar[1];
+        let mut result = DMatrix::from_element(2, 2, 0.0);
+        result[(0, 0)] = phi.cos();
+        result[(0, 1)] = -r * phi.sin();
+        result[(1, 0)] = phi.sin();
+        result[(1, 1)] = r * phi.cos();
+        result
+    }
+
+    /// `first_order_forward_batch` matches stacking single-direction `first_order_forward` calls
+    /// into columns
+    #[test]
+    fn first_order_forward_batch_matches_single_direction() {
+        let polar = DVector::from_vec(vec![2.0, std::f64::consts::FRAC_PI_4]);
+        let tape = polar_to_cartesian_tape(&polar);
+
+        let dx = DMatrix::<f64>::identity(2, 2);
+        let batched = tape.first_order_forward_batch(&dx);
+
+        assert_eq!(batched, polar_reference_jacobian(&polar));
+    }
+
+    /// `first_order_reverse_batch` matches stacking single-direction `first_order_reverse` calls
+    /// into columns (the result is the transpose of the Jacobian, same as `first_order_reverse`
+    /// itself produces one row of it per call)
+    #[test]
+    fn first_order_reverse_batch_matches_single_direction() {
+        let polar = DVector::from_vec(vec![2.0, std::f64::consts::FRAC_PI_4]);
+        let tape = polar_to_cartesian_tape(&polar);
+
+        let ybar = DMatrix::<f64>::identity(2, 2);
+        let batched = tape.first_order_reverse_batch(&ybar);
+
+        assert_eq!(batched.transpose(), polar_reference_jacobian(&polar));
+    }
+
+    /// `jacobian` picks the cheaper of forward/reverse batching and matches the dense reference
+    #[test]
+    fn jacobian_matches_reference() {
+        let polar = DVector::from_vec(vec![2.0, std::f64::consts::FRAC_PI_4]);
+        let tape = polar_to_cartesian_tape(&polar);
+
+        assert_eq!(tape.jacobian(), polar_reference_jacobian(&polar));
+    }
+
+    adv_fn! {
+        fn quadratic_test_func(input: [[2]]) -> [[1]] {
+            let x = input[0];
+            let y = input[1];
+            adv_dvec![x * x * y]
+        }
+    }
+
+    /// `second_order_reverse` matches `first_order_reverse`'s gradient and the closed-form
+    /// Hessian-vector product of `x^2*y`
+    #[test]
+    fn second_order_reverse_matches_reference_hessian() {
+        let point = DVector::from_vec(vec![3.0, 2.0]);
+        let tape = {
+            let mut ctx = AContext::new();
+            let input = DVector::from_vec(ctx.new_indep_vec(2, 0.0));
+            let output = quadratic_test_func(input);
+            ctx.set_dep_slice(output.as_slice());
+            let mut tape = ctx.tape();
+            tape.zero_order(&point);
+            tape
+        };
+
+        let x = point[0];
+        let y = point[1];
+        let hessian = DMatrix::from_row_slice(2, 2, &[2.0 * y, 2.0 * x, 2.0 * x, 0.0]);
+
+        let dx = DVector::from_vec(vec![1.0, -1.0]);
+        let ybar = DVector::from_element(1, 1.0);
+        let (gradient, hvp) = tape.second_order_reverse(&dx, &ybar);
+
+        assert_eq!(gradient, tape.first_order_reverse(&ybar));
+        assert_eq!(hvp, hessian * dx);
+    }
 }